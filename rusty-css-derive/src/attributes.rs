@@ -0,0 +1,126 @@
+use syn::{ Attribute, Expr, ExprLit, Lit, LitStr, Result };
+
+// the values given in a fields #[css(...)] attribute
+#[derive(Default)]
+pub struct FieldAttributes {
+    // #[css(default = ...)]
+    pub default: Option<DefaultValue>,
+    // #[css(unit = "px")]
+    pub unit: Option<LitStr>,
+    // #[css(custom)], the field is a custom property (--field-name)
+    pub custom: bool,
+    // #[css(rename = "-webkit-line-clamp")], the property name of the field
+    pub rename: Option<LitStr>,
+    // #[css(nest = "&:hover")], the field is a style of its own that is nested in this one
+    pub nest: Option<LitStr>,
+    // #[css(media = "(min-width: 600px)")] or #[css(media = MediaQuery::new().print())], the field is a style
+    // of its own that applies while the media query matches
+    pub media: Option<Expr>,
+    // #[css(container = ...)] and #[css(supports = ...)], like media but for container and feature queries
+    pub container: Option<Expr>,
+    pub supports: Option<Expr>,
+}
+
+pub enum DefaultValue {
+    // #[css(default = "4em")], css text that is parsed into the field like an inline style would be
+    Css(LitStr),
+    // #[css(default = 4.0)], any other rust expression is used as-is
+    Expr(Expr),
+}
+
+impl FieldAttributes {
+    pub fn from_attributes(attributes: &[Attribute]) -> Result<Self> {
+        let mut field_attributes = Self::default();
+
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("css")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    let value: Expr = meta.value()?.parse()?;
+                    field_attributes.default = Some(match value {
+                        Expr::Lit(ExprLit { lit: Lit::Str(css), .. }) => DefaultValue::Css(css),
+                        expr => DefaultValue::Expr(expr),
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("unit") {
+                    field_attributes.unit = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("custom") {
+                    field_attributes.custom = true;
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    field_attributes.rename = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("nest") {
+                    field_attributes.nest = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("media") {
+                    field_attributes.media = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("container") {
+                    field_attributes.container = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("supports") {
+                    field_attributes.supports = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown css attribute, expected `default`, `unit`, `custom`, `rename`, `nest`, `media`, `container` or `supports`"))
+                }
+            })?;
+        }
+
+        Ok(field_attributes)
+    }
+}
+
+// the values given in a structs #[css(...)] attribute
+#[derive(Default)]
+pub struct StructAttributes {
+    // #[css(selector = ":hover")], css text that is parsed into a Selector,
+    // or #[css(selector = Selector::new().hover())], an expression that is used as-is
+    pub selector: Option<Expr>,
+    // #[css(media = "(min-width: 600px)")], a media query that is written as-is,
+    // or #[css(media = MediaQuery::new().print())], an expression that is used as-is
+    pub media: Option<Expr>,
+    // #[css(container = ...)] and #[css(supports = ...)], like media but for container and feature queries
+    pub container: Option<Expr>,
+    pub supports: Option<Expr>,
+    // #[css(naming = "module")], one of "type", "module" or "hash",
+    // or #[css(naming = ClassNaming::Hash)], an expression that is used as-is
+    pub naming: Option<Expr>,
+    // #[css(prefix = "app")], the class is named after the type with the prefix in front
+    pub prefix: Option<LitStr>,
+}
+
+impl StructAttributes {
+    pub fn from_attributes(attributes: &[Attribute]) -> Result<Self> {
+        let mut struct_attributes = Self::default();
+
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("css")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("selector") {
+                    struct_attributes.selector = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("media") {
+                    struct_attributes.media = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("container") {
+                    struct_attributes.container = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("supports") {
+                    struct_attributes.supports = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("naming") {
+                    struct_attributes.naming = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("prefix") {
+                    struct_attributes.prefix = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown css attribute for a struct, expected `selector`, `media`, `container`, `supports`, `naming` or `prefix`"))
+                }
+            })?;
+        }
+
+        Ok(struct_attributes)
+    }
+}
@@ -0,0 +1,409 @@
+//! Derive macros for the rusty-css crate. Use them through the re-exports in `rusty_css`.
+
+mod attributes;
+
+use attributes::{ DefaultValue, FieldAttributes, StructAttributes };
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{ parse_macro_input, Data, DeriveInput, Error, Expr, ExprLit, Fields, Lit, LitStr, GenericArgument, PathArguments, Type };
+
+/// Implements `rusty_css::Style` for a struct with named fields, generating `create()`.
+///
+/// Every field starts out as its `Default::default()`. A field can be given another
+/// initial value with `#[css(default = ...)]`:
+/// - a string literal is read as css, the same way `try_set_from_inline_string` would read it, and `create()`
+///   panics with the name of the field if it can't be read
+///   (`#[css(default = "skewX(20deg) skewY(30deg)")]` works for a nested struct)
+/// - any other expression is used as the fields value directly (`#[css(default = 4.0)]`)
+///
+/// Number fields can be given a unit with `#[css(unit = "px")]`, which is appended to the numbers
+/// when rendering and stripped from them when parsing.
+///
+/// Fields are written and read as their kebab-cased name (`border_radius` is `border-radius`, `r#type` is `type`).
+/// `#[css(rename = "-webkit-line-clamp")]` gives a field any other property name, and fields with a
+/// `#[css(custom)]` attribute are css custom properties, `brand_color` is written and read as `--brand-color`.
+/// The fields of a nested struct are written and read as css functions named the same way, a nested struct that
+/// derives Style too can rename them (`#[css(rename = "skewX")]`).
+///
+/// `Option<T>` fields are left out of the css while they're `None`. If `T` implements `Default`,
+/// they're filled with a `T::default()` that the css is parsed into when the property appears.
+/// `Vec<T>` fields grow the same way when the css has more values than the `Vec`.
+///
+/// A field with a `#[css(nest = "&:hover")]` attribute holds a style of its own, which `as_class_string`
+/// writes as another rule with `&` standing for the selector of this style. It starts out as its `create()`.
+///
+/// A field with a `#[css(media = "(min-width: 600px)")]` attribute is nested the same way, but its rule is
+/// wrapped in the media query, so it can override the style on some devices. `#[css(container = ...)]` and
+/// `#[css(supports = ...)]` do the same with container and feature queries, all of them can be combined.
+///
+/// The selector that follows the class name in `as_class_string` is given on the struct with
+/// `#[css(selector = ":hover")]`, either as css text or as an expression that evaluates to a `Selector`.
+/// All rules of the struct can be wrapped in conditions with `#[css(media = ...)]`, `#[css(container = ...)]`
+/// and `#[css(supports = ...)]` on the struct.
+///
+/// The class is named after the type with the path of its module in front of it. `#[css(naming = "hash")]` puts
+/// a hash of that path after the name instead, `#[css(prefix = "app")]` the given prefix in front of it and
+/// `#[css(naming = "type")]` uses the bare name of the type.
+///
+/// The struct still has to derive `Reflect` itself.
+#[proc_macro_derive(Style, attributes(css))]
+pub fn derive_style(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_style(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand_style(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "Style can only be derived for structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "Style can only be derived for structs with named fields")),
+    };
+
+    let mut initializers = Vec::new();
+    let mut css_defaults = Vec::new();
+    let mut units = Vec::new();
+    let mut blank_items = Vec::new();
+    let mut properties = Vec::new();
+    let mut nests = Vec::new();
+    let mut nested_rules = Vec::new();
+    let mut value_types: Vec<&Type> = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let attributes = FieldAttributes::from_attributes(&field.attrs)?;
+
+        let mut property_name = ident.unraw().to_string().replace('_', "-");
+        match (&attributes.rename, attributes.custom) {
+            (Some(rename), true) => return Err(Error::new_spanned(rename, "`rename` can't be combined with `custom`, rename the field to `--name` instead")),
+            (Some(rename), false) => property_name = rename.value(),
+            (None, true) => property_name = format!("--{}", property_name),
+            (None, false) => {},
+        }
+        if attributes.rename.is_some() || attributes.custom {
+            let field_name = ident.to_string();
+            properties.push(quote! { #field_name => ::std::option::Option::Some(#property_name) });
+        }
+
+        if let Some(inner) = inner_type(&field.ty, "Option").or_else(|| inner_type(&field.ty, "Vec")) {
+            let field_name = ident.to_string();
+            blank_items.push(quote! { #field_name => (&&::rusty_css::__private::Blank::<#inner>::new()).blank() });
+        }
+
+        if let Some(unit) = attributes.unit {
+            let field_name = ident.to_string();
+            units.push(quote! { #field_name => ::std::option::Option::Some(#unit) });
+        }
+
+        // nested styles are created as styles of their own, with the css default read into them directly
+        let conditions = [
+            (&attributes.supports, quote! { ::rusty_css::SupportsCondition }),
+            (&attributes.container, quote! { ::rusty_css::ContainerQuery }),
+            (&attributes.media, quote! { ::rusty_css::MediaQuery }),
+        ];
+        if attributes.nest.is_some() || conditions.iter().any(|(condition, _)| condition.is_some()) {
+            if attributes.rename.is_some() || attributes.custom {
+                return Err(Error::new_spanned(ident, "a nested style isn't a property, so it can't be renamed"));
+            }
+            let field_name = ident.to_string();
+            let ty = &field.ty;
+            let nest = attributes.nest.as_ref().map(LitStr::value).unwrap_or_else(|| "&".to_owned());
+            nests.push(quote! { #field_name => ::std::option::Option::Some(#nest) });
+            let mut rules = quote! { ::rusty_css::Style::as_rule_string(&self.#ident, &::rusty_css::selector::nest(#nest, selector)) };
+            // @media ends up outermost, like in as_class_string
+            for (condition, ty) in conditions {
+                if let Some(condition) = condition {
+                    let condition = condition_expr(condition, &ty);
+                    rules = quote! { #condition.wrap(&#rules) };
+                }
+            }
+            nested_rules.push(rules);
+            initializers.push(match attributes.default {
+                Some(DefaultValue::Expr(expr)) => quote! { #ident: #expr },
+                Some(DefaultValue::Css(css)) => {
+                    check_css(&css, true)?;
+                    quote! {
+                        #ident: {
+                            let mut nested = <#ty as ::rusty_css::Style>::create();
+                            ::rusty_css::Style::try_set_from_inline_string(&mut nested, #css)
+                                .unwrap_or_else(|error| panic!("invalid default for field `{}` ({})", #field_name, error));
+                            nested
+                        }
+                    }
+                },
+                None => quote! { #ident: <#ty as ::rusty_css::Style>::create() },
+            });
+            continue;
+        }
+
+        // the type of a value that can be a nested struct, which might rename its fields
+        let value_type = inner_type(&field.ty, "Option").or_else(|| inner_type(&field.ty, "Vec")).unwrap_or(&field.ty);
+        if !value_types.iter().any(|ty| quote!(#ty).to_string() == quote!(#value_type).to_string()) {
+            value_types.push(value_type);
+        }
+
+        match attributes.default {
+            Some(DefaultValue::Expr(expr)) => initializers.push(quote! { #ident: #expr }),
+            Some(DefaultValue::Css(css)) => {
+                check_css(&css, false)?;
+                initializers.push(quote! { #ident: ::std::default::Default::default() });
+                let field_name = ident.to_string();
+                let declaration = format!("{}: {};", property_name, css.value());
+                css_defaults.push(quote! {
+                    ::rusty_css::Style::try_set_from_inline_string(&mut style, #declaration)
+                        .unwrap_or_else(|error| panic!("invalid default for field `{}` ({})", #field_name, error));
+                });
+            },
+            None => initializers.push(quote! { #ident: ::std::default::Default::default() }),
+        }
+    }
+
+    let struct_attributes = StructAttributes::from_attributes(&input.attrs)?;
+    let selector = match &struct_attributes.selector {
+        Some(Expr::Lit(ExprLit { lit: Lit::Str(css), .. })) => {
+            check_css(css, false)?;
+            let name_string = input.ident.to_string();
+            quote! {
+                fn selector(&self) -> ::rusty_css::Selector {
+                    #css.parse().unwrap_or_else(|error| panic!("invalid selector `{}` of `{}` ({})", #css, #name_string, error))
+                }
+            }
+        },
+        Some(expr) => quote! {
+            fn selector(&self) -> ::rusty_css::Selector {
+                #expr
+            }
+        },
+        None => quote! {},
+    };
+
+    let naming = match (&struct_attributes.naming, &struct_attributes.prefix) {
+        (Some(naming), Some(_)) => return Err(Error::new_spanned(naming, "`naming` can't be combined with `prefix`")),
+        (Some(Expr::Lit(ExprLit { lit: Lit::Str(naming), .. })), None) => {
+            let strategy = match naming.value().as_str() {
+                "type" => quote! { TypeName },
+                "module" => quote! { ModulePath },
+                "hash" => quote! { Hash },
+                _ => return Err(Error::new_spanned(naming, "expected `type`, `module` or `hash`")),
+            };
+            Some(quote! { ::rusty_css::ClassNaming::#strategy })
+        },
+        (Some(expr), None) => Some(quote! { (#expr) }),
+        (None, Some(prefix)) => Some(quote! { ::rusty_css::ClassNaming::Prefix(::std::string::String::from(#prefix)) }),
+        (None, None) => None,
+    };
+    // the generic arguments of the type can only be known from its type_name
+    let name_string = input.ident.to_string();
+    let type_path = if input.generics.params.is_empty() {
+        quote! { ::std::string::String::from(::std::concat!(::std::module_path!(), "::", #name_string)) }
+    } else {
+        quote! {{
+            let type_name = ::std::any::type_name::<Self>();
+            let generics = type_name.find('<').map(|start| &type_name[start..]).unwrap_or_default();
+            ::std::format!("{}::{}{}", ::std::module_path!(), #name_string, generics)
+        }}
+    };
+
+    let class_naming = naming.map(|naming| quote! {
+        fn class_naming(&self) -> ::rusty_css::ClassNaming {
+            #naming
+        }
+    });
+
+    let mut conditions = Vec::new();
+    for (condition, method, ty) in [
+        (&struct_attributes.media, quote! { media }, quote! { ::rusty_css::MediaQuery }),
+        (&struct_attributes.container, quote! { container }, quote! { ::rusty_css::ContainerQuery }),
+        (&struct_attributes.supports, quote! { supports }, quote! { ::rusty_css::SupportsCondition }),
+    ] {
+        if let Some(condition) = condition {
+            let condition = condition_expr(condition, &ty);
+            conditions.push(quote! {
+                fn #method(&self) -> ::std::option::Option<#ty> {
+                    ::std::option::Option::Some(#condition)
+                }
+            });
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let field_unit = if units.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn field_unit(field_name: &str) -> ::std::option::Option<&'static str> {
+                match field_name {
+                    #( #units, )*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    };
+
+    let field_property = if properties.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn field_property(field_name: &str) -> ::std::option::Option<&'static str> {
+                match field_name {
+                    #( #properties, )*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    };
+
+    let nested = if nests.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn field_nest(field_name: &str) -> ::std::option::Option<&'static str> {
+                match field_name {
+                    #( #nests, )*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn nested_rules(&self, selector: &str) -> ::std::vec::Vec<::std::string::String> {
+                ::std::vec![ #( #nested_rules, )* ]
+            }
+        }
+    };
+
+    let nested_property = if value_types.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn nested_property(nested_type: ::std::any::TypeId, field_name: &str) -> ::std::option::Option<::std::string::String> {
+                #[allow(unused_imports)]
+                use ::rusty_css::__private::{ RenamesStyle, RenamesFallback };
+                #(
+                    if let ::std::option::Option::Some(name) = (&&::rusty_css::__private::Renames::<#value_types>::new()).nested_property(nested_type, field_name) {
+                        return ::std::option::Option::Some(name);
+                    }
+                )*
+                ::std::option::Option::None
+            }
+        }
+    };
+
+    let blank_field_item = if blank_items.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn blank_field_item(field_name: &str) -> ::std::option::Option<::std::boxed::Box<dyn ::bevy_reflect::Reflect>> {
+                #[allow(unused_imports)]
+                use ::rusty_css::__private::{ BlankDefault, BlankFallback };
+                match field_name {
+                    #( #blank_items, )*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::rusty_css::Style for #name #ty_generics #where_clause {
+            fn create() -> Self {
+                #[allow(unused_mut)]
+                let mut style = Self {
+                    #( #initializers, )*
+                };
+                // the css defaults are applied on top of the plain defaults
+                #( #css_defaults )*
+                style
+            }
+
+            #field_unit
+
+            #field_property
+
+            #blank_field_item
+
+            #nested_property
+
+            #selector
+
+            #class_naming
+
+            fn type_path(&self) -> ::std::string::String {
+                #type_path
+            }
+
+            #( #conditions )*
+
+            #nested
+        }
+    })
+}
+
+// a condition given as css text is written as-is, any other expression is used as the condition type
+fn condition_expr(condition: &Expr, ty: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    match condition {
+        Expr::Lit(ExprLit { lit: Lit::Str(css), .. }) => quote! { #ty::raw(#css) },
+        expr => quote! { (#expr) },
+    }
+}
+
+// the T in a field of type Option<T> or Vec<T>
+fn inner_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper { return None; }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// catches css text in an attribute that can't be read at all while expanding the macro: unbalanced brackets
+// and quotes, blocks and (unless it's a list of declarations) semicolons. Everything else is read at runtime
+fn check_css(css: &LitStr, declarations: bool) -> syn::Result<()> {
+    let text = css.value();
+    let error = |message: String| Err(Error::new_spanned(css, message));
+    let mut open = Vec::new();
+    let mut quote = None;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (_, '\\') => { chars.next(); },
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {},
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '/') if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                loop {
+                    match chars.next() {
+                        Some('/') if previous == '*' => break,
+                        Some(c) => previous = c,
+                        None => return error(format!("unclosed comment in `{}`", text)),
+                    }
+                }
+            },
+            (None, '(' | '[') => open.push(c),
+            (None, ')' | ']') => {
+                let expected = if c == ')' { '(' } else { '[' };
+                if open.pop() != Some(expected) {
+                    return error(format!("unexpected `{}` in `{}`", c, text));
+                }
+            },
+            (None, '{' | '}') => return error(format!("`{}` can't be used in `{}`", c, text)),
+            (None, ';') if !declarations && open.is_empty() => return error(format!("`;` can't be used in `{}`", text)),
+            _ => {},
+        }
+    }
+
+    match (quote, open.last()) {
+        (Some(q), _) => error(format!("unclosed `{}` in `{}`", q, text)),
+        (None, Some(c)) => error(format!("unclosed `{}` in `{}`", c, text)),
+        (None, None) => Ok(()),
+    }
+}
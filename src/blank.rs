@@ -0,0 +1,121 @@
+// creates fresh values for fields that don't hold one yet (i.e. an Option that is None)
+
+use bevy_reflect::{ DynamicEnum, DynamicList, DynamicStruct, DynamicTuple, DynamicTupleStruct, DynamicVariant, Reflect, ReflectRef };
+use std::any::{ type_name, TypeId };
+use std::marker::PhantomData;
+use std::sync::RwLock;
+
+// creates the default of a type given to register_default()
+type DefaultFn = fn() -> Box<dyn Reflect>;
+
+// the defaults of the types given to register_default(), a list only knows the TypeId of its items
+static DEFAULTS: RwLock<Vec<(TypeId, DefaultFn)>> = RwLock::new(Vec::new());
+
+/// Lets lists of `T` grow when css with more values than they hold is read into them, by creating the new elements
+/// as `T::default()`. `#[derive(Style)]` does this for the `Vec` fields of the struct itself already, lists inside of
+/// nested structs need their item type registered once, i.e. `register_default::<Shadow>()` for a `Vec<Shadow>`.
+pub fn register_default<T: Default + Reflect>() {
+    fn default_of<T: Default + Reflect>() -> Box<dyn Reflect> {
+        Box::<T>::default()
+    }
+
+    let mut defaults = DEFAULTS.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if !defaults.iter().any(|(type_id, _)| *type_id == TypeId::of::<T>()) {
+        defaults.push((TypeId::of::<T>(), default_of::<T>));
+    }
+}
+
+// the default of a type given to register_default()
+pub(crate) fn registered_default(type_id: TypeId) -> Option<Box<dyn Reflect>> {
+    let defaults = DEFAULTS.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    defaults.iter().find(|(registered, _)| *registered == type_id).map(|(_, default)| default())
+}
+
+// a fresh String, bool, number or rusty_css::values type of the type with the given name
+pub(crate) fn blank_value(value_type_name: &str) -> Option<Box<dyn Reflect>> {
+    macro_rules! blank_of {
+        ($($value:ty),*) => {
+            $(
+                if value_type_name == type_name::<$value>() {
+                    return Some(Box::<$value>::default());
+                }
+            )*
+        };
+    }
+    blank_of!(String, bool, f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+    crate::values::blank_value(value_type_name)
+}
+
+// a value of the same type as the given one with a blank value in every field, which FromReflect turns into
+// the type itself (i.e. when it's pushed into a Vec). Only the types of the fields are taken from the given value,
+// Options start out as None and Vecs empty
+pub(crate) fn blank_like(value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+    if let Some(blank) = blank_value(value.type_name()) {
+        return Some(blank);
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(struct_reflect) => {
+            let mut blank = DynamicStruct::default();
+            for (i, field) in struct_reflect.iter_fields().enumerate() {
+                blank.insert_boxed(struct_reflect.name_at(i)?, blank_like(field)?);
+            }
+            Some(Box::new(blank))
+        },
+        ReflectRef::TupleStruct(tuple_struct) => {
+            let mut blank = DynamicTupleStruct::default();
+            for field in tuple_struct.iter_fields() {
+                blank.insert_boxed(blank_like(field)?);
+            }
+            Some(Box::new(blank))
+        },
+        ReflectRef::Tuple(tuple) => {
+            let mut blank = DynamicTuple::default();
+            for field in tuple.iter_fields() {
+                blank.insert_boxed(blank_like(field)?);
+            }
+            Some(Box::new(blank))
+        },
+        ReflectRef::List(_) => Some(Box::<DynamicList>::default()),
+        ReflectRef::Enum(enum_reflect) if crate::option::is_option(enum_reflect) => {
+            Some(Box::new(DynamicEnum::new(value.type_name().to_owned(), "None".to_owned(), DynamicVariant::Unit)))
+        },
+        _ => None,
+    }
+}
+
+// #[derive(Style)] uses this to get a T::default() for every field type T that implements Default,
+// and None for every other type, without having to know which is which:
+// (&&Blank::<T>::new()).blank() picks BlankDefault if it applies and falls back to BlankFallback otherwise
+#[doc(hidden)]
+pub struct Blank<T>(PhantomData<T>);
+
+impl<T> Blank<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Blank(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait BlankDefault {
+    fn blank(&self) -> Option<Box<dyn Reflect>>;
+}
+
+impl<T: Default + Reflect> BlankDefault for &Blank<T> {
+    fn blank(&self) -> Option<Box<dyn Reflect>> {
+        Some(Box::<T>::default())
+    }
+}
+
+#[doc(hidden)]
+pub trait BlankFallback {
+    fn blank(&self) -> Option<Box<dyn Reflect>>;
+}
+
+impl<T> BlankFallback for Blank<T> {
+    fn blank(&self) -> Option<Box<dyn Reflect>> {
+        None
+    }
+}
@@ -0,0 +1,114 @@
+//! Container queries that rules are wrapped in, like `@container sidebar (min-width: 400px) { ... }`.
+//! The container itself needs a `container-type` (and a `container-name` to be queried by name).
+
+use crate::media::Orientation;
+use crate::values::Length;
+use std::fmt;
+
+// a condition of a container query, every one of them has to be met
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContainerFeature {
+    MinWidth(Length),
+    MaxWidth(Length),
+    MinHeight(Length),
+    MaxHeight(Length),
+    MinInlineSize(Length),
+    MaxInlineSize(Length),
+    Orientation(Orientation),
+    // any other condition, written as-is (i.e. `(aspect-ratio > 1)` or `style(--compact: true)`)
+    Raw(String),
+}
+
+impl fmt::Display for ContainerFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContainerFeature::MinWidth(width) => write!(f, "(min-width: {})", width),
+            ContainerFeature::MaxWidth(width) => write!(f, "(max-width: {})", width),
+            ContainerFeature::MinHeight(height) => write!(f, "(min-height: {})", height),
+            ContainerFeature::MaxHeight(height) => write!(f, "(max-height: {})", height),
+            ContainerFeature::MinInlineSize(size) => write!(f, "(min-inline-size: {})", size),
+            ContainerFeature::MaxInlineSize(size) => write!(f, "(max-inline-size: {})", size),
+            ContainerFeature::Orientation(Orientation::Portrait) => write!(f, "(orientation: portrait)"),
+            ContainerFeature::Orientation(Orientation::Landscape) => write!(f, "(orientation: landscape)"),
+            ContainerFeature::Raw(condition) => write!(f, "{}", condition.trim()),
+        }
+    }
+}
+
+/// A container query on the nearest container, or on the nearest one with the given name,
+/// written as `sidebar (min-width: 400px) and (orientation: portrait)`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContainerQuery {
+    pub name: Option<String>,
+    pub features: Vec<ContainerFeature>,
+}
+
+impl ContainerQuery {
+    // a query on the nearest container
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // a query on the nearest container with the given container-name
+    pub fn named(name: &str) -> Self {
+        Self { name: Some(name.to_owned()), features: Vec::new() }
+    }
+
+    // a query that is written as-is (i.e. `sidebar (width > 400px)`)
+    pub fn raw(query: &str) -> Self {
+        Self::new().and(ContainerFeature::Raw(query.to_owned()))
+    }
+
+    pub fn and(mut self, feature: ContainerFeature) -> Self {
+        self.features.push(feature);
+        self
+    }
+
+    pub fn min_width(self, width: Length) -> Self {
+        self.and(ContainerFeature::MinWidth(width))
+    }
+
+    pub fn max_width(self, width: Length) -> Self {
+        self.and(ContainerFeature::MaxWidth(width))
+    }
+
+    pub fn min_height(self, height: Length) -> Self {
+        self.and(ContainerFeature::MinHeight(height))
+    }
+
+    pub fn max_height(self, height: Length) -> Self {
+        self.and(ContainerFeature::MaxHeight(height))
+    }
+
+    pub fn min_inline_size(self, size: Length) -> Self {
+        self.and(ContainerFeature::MinInlineSize(size))
+    }
+
+    pub fn max_inline_size(self, size: Length) -> Self {
+        self.and(ContainerFeature::MaxInlineSize(size))
+    }
+
+    pub fn orientation(self, orientation: Orientation) -> Self {
+        self.and(ContainerFeature::Orientation(orientation))
+    }
+
+    // the rules wrapped in an @container block for this query,
+    // a query without a name or features holds for every container, so the rules are left unwrapped
+    pub fn wrap(&self, rules: &str) -> String {
+        if self.name.is_none() && self.features.is_empty() {
+            return rules.to_owned();
+        }
+        format!("@container {} {{ {} }}", self, rules)
+    }
+}
+
+impl fmt::Display for ContainerQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let features = self.features.iter().map(ContainerFeature::to_string).collect::<Vec<String>>().join(" and ");
+        match &self.name {
+            Some(name) if !features.is_empty() => write!(f, "{} {}", name, features),
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "{}", features),
+        }
+    }
+}
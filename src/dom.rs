@@ -0,0 +1,38 @@
+// puts css into the <head> of a document
+
+use crate::error::Error;
+use web_sys::Document;
+
+// the id of the <style> element a name is put into,
+// anything that can't be in an id without escaping it (i.e. the : of a pseudo-class) is replaced by _
+pub(crate) fn style_id(name: &str) -> String {
+    let name = name.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' }).collect::<String>();
+    format!("rusty-css-{}", name)
+}
+
+// the css as the content of a <style> element in html, </ would end the element early and <\/ means the same in css
+pub(crate) fn escape_style_text(css: &str) -> String {
+    css.replace("</", "<\\/")
+}
+
+// a <style> element with the id rusty-css-{name} and the css as its content,
+// replacing the element that was put there under the same name before
+pub(crate) fn append_style_element(document: &Document, name: &str, css: &str) -> Result<(), Error> {
+    let head = document.head().ok_or(Error::MissingHead)?;
+    let style_id = style_id(name);
+
+    if let Some(existent_style) = head.query_selector(&format!("#{}", style_id) )? {
+        // an element with the same css (i.e. rendered on the server, where it was escaped) is kept as it is
+        let text = existent_style.text_content().unwrap_or_default();
+        if text == css || text == escape_style_text(css) {
+            return Ok(());
+        }
+        head.remove_child(&existent_style)?;
+    }
+
+    let new_style_element = document.create_element("style")?;
+    new_style_element.set_attribute("id", &style_id )?;
+    new_style_element.set_text_content(Some(css));
+    head.append_child(&new_style_element)?;
+    Ok(())
+}
@@ -0,0 +1,80 @@
+// enums are written as css keywords (unit variants) and css functions (tuple and struct variants)
+//   Display::InlineFlex            -> inline-flex
+//   Transform::TranslateX(10.0)    -> translateX(10)
+//   Rotation::Rotate3d(a, b, c, d) -> rotate3d(a, b, c, d)
+//   Shadow::DropShadow(a, b, c)    -> drop-shadow(a b c)
+
+use crate::error::{ offset_in, Error };
+use crate::split;
+use bevy_reflect::{ Enum, TypeInfo, VariantInfo };
+
+// the css functions whose arguments are separated by whitespace, all others separate them by commas
+const WHITESPACE_FUNCTIONS: &[&str] = &["drop-shadow", "inset", "circle", "ellipse", "hwb", "lab", "lch", "oklab", "oklch"];
+
+// InlineFlex -> inline-flex, RepeatX -> repeat-x
+pub(crate) fn keyword_name(variant_name: &str) -> String {
+    let mut keyword = String::new();
+    for (i, c) in variant_name.char_indices() {
+        if c.is_uppercase() && i != 0 { keyword.push('-'); }
+        keyword.extend(c.to_lowercase());
+    }
+
+    keyword
+}
+
+// DropShadow -> drop-shadow, but TranslateX -> translateX, since uppercase letters that don't start a word are kept as-is
+pub(crate) fn function_name(variant_name: &str) -> String {
+    let chars = variant_name.chars().collect::<Vec<char>>();
+    let mut function = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        let starts_word = c.is_uppercase() && chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+        if i == 0 || starts_word {
+            if i != 0 { function.push('-'); }
+            function.extend(c.to_lowercase());
+        } else {
+            function.push(*c);
+        }
+    }
+
+    function
+}
+
+// the variants of the enum behind the reflect
+pub(crate) fn variants(enum_reflect: &dyn Enum) -> &'static [VariantInfo] {
+    match enum_reflect.get_type_info() {
+        TypeInfo::Enum(info) => {
+            let variants: &'static [VariantInfo] = info.iter().as_slice();
+            variants
+        },
+        _ => &[],
+    }
+}
+
+// the name a variant has in css
+pub(crate) fn css_name(variant: &VariantInfo) -> String {
+    match variant {
+        VariantInfo::Unit(unit) => keyword_name(unit.name()),
+        VariantInfo::Tuple(tuple) => function_name(tuple.name()),
+        VariantInfo::Struct(named) => function_name(named.name()),
+    }
+}
+
+// the separator that is written between the arguments of the css function
+pub(crate) fn argument_separator(function_name: &str) -> &'static str {
+    if WHITESPACE_FUNCTIONS.contains(&function_name) { " " } else { ", " }
+}
+
+// the arguments of the css function, split at whitespace or commas depending on the function
+pub(crate) fn split_arguments<'a>(function_name: &str, args: &'a str) -> Result<Vec<&'a str>, Error> {
+    if !WHITESPACE_FUNCTIONS.contains(&function_name) {
+        return Ok(split::split_commas(args));
+    }
+
+    let commas = split::split_commas(args);
+    if commas.len() > 1 {
+        let after_first = &args[offset_in(args, commas[0]) + commas[0].len()..];
+        let comma = split::trim(after_first);
+        return Err(Error::parse(offset_in(args, comma), format!("`{}()` separates its arguments by whitespace, found `,`", function_name)));
+    }
+    Ok(split::split_whitespace(args, usize::MAX))
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+use wasm_bindgen::JsValue;
+
+// everything that can go wrong while reading css into a struct or exporting a struct as a class
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    // the css couldn't be parsed, offset is the byte offset into the string that was handed to the failing method
+    Parse { offset: usize, message: String },
+    // the css contains a property that has no corresponding field in the struct
+    UnknownProperty(String),
+    // a field has a type that the css can't be written into or read from
+    TypeMismatch { expected: String, found: String },
+    // a call into the DOM failed
+    Dom(String),
+    // the document has no <head> element to put the <style> element into
+    MissingHead,
+}
+
+impl Error {
+    pub(crate) fn parse<S: Into<String>>(offset: usize, message: S) -> Self {
+        Error::Parse { offset, message: message.into() }
+    }
+
+    pub(crate) fn type_mismatch<S: Into<String>>(expected: S, found: &str) -> Self {
+        Error::TypeMismatch { expected: expected.into(), found: found.to_owned() }
+    }
+
+    // moves the offset of a parse error from a substring into the string containing it
+    pub(crate) fn offset_by(self, by: usize) -> Self {
+        match self {
+            Error::Parse { offset, message } => Error::Parse { offset: offset + by, message },
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse { offset, message } => write!(f, "couldn't parse the css at byte {}: {}", offset, message),
+            Error::UnknownProperty(property) => write!(f, "no field corresponds to the css property `{}`", property),
+            Error::TypeMismatch { expected, found } => write!(f, "expected a field of type {}, found {}", expected, found),
+            Error::Dom(message) => write!(f, "a DOM operation failed: {}", message),
+            Error::MissingHead => write!(f, "no <head> element found in the document"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<JsValue> for Error {
+    fn from(value: JsValue) -> Self {
+        Error::Dom(format!("{:?}", value))
+    }
+}
+
+// byte offset of a substring within the string it was sliced from
+pub(crate) fn offset_in(outer: &str, inner: &str) -> usize {
+    inner.as_ptr() as usize - outer.as_ptr() as usize
+}
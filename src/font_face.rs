@@ -0,0 +1,166 @@
+//! `@font-face` rules that load custom fonts.
+
+use crate::registry::StyleRegistry;
+use crate::error::Error;
+use std::fmt;
+
+// a quoted css string, `"` and `\` are escaped
+pub(crate) fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// where the browser can get a font from
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSource {
+    // url("font.woff2") format("woff2")
+    Url { url: String, format: Option<String> },
+    // local("Font Name"), a font that is installed on the device
+    Local(String),
+}
+
+impl FontSource {
+    pub fn url(url: &str) -> Self {
+        FontSource::Url { url: url.to_owned(), format: None }
+    }
+
+    pub fn local(name: &str) -> Self {
+        FontSource::Local(name.to_owned())
+    }
+
+    // the format hint of a url (woff2, woff, opentype, truetype, ...)
+    pub fn format(self, format: &str) -> Self {
+        match self {
+            FontSource::Url { url, .. } => FontSource::Url { url, format: Some(format.to_owned()) },
+            local => local,
+        }
+    }
+}
+
+impl fmt::Display for FontSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontSource::Url { url, format: None } => write!(f, "url({})", quote(url)),
+            FontSource::Url { url, format: Some(format) } => write!(f, "url({}) format({})", quote(url), quote(format)),
+            FontSource::Local(name) => write!(f, "local({})", quote(name)),
+        }
+    }
+}
+
+// what is shown while the font is loading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontDisplay {
+    Auto,
+    Block,
+    Swap,
+    Fallback,
+    Optional,
+}
+
+impl fmt::Display for FontDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontDisplay::Auto => write!(f, "auto"),
+            FontDisplay::Block => write!(f, "block"),
+            FontDisplay::Swap => write!(f, "swap"),
+            FontDisplay::Fallback => write!(f, "fallback"),
+            FontDisplay::Optional => write!(f, "optional"),
+        }
+    }
+}
+
+/// A font family and the files it's loaded from, written as `@font-face { font-family: "Inter"; src: ...; }`.
+/// The family can then be used in any `font-family` property.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontFace {
+    pub family: String,
+    // tried one after the other until the browser finds one it can use
+    pub src: Vec<FontSource>,
+    // the weights the font covers, a single weight if both are the same (400 or 100 900)
+    pub weight: Option<(u16, u16)>,
+    // normal, italic or oblique
+    pub style: Option<String>,
+    pub display: Option<FontDisplay>,
+    // the code points the font is used for, each range from its first to its last code point
+    pub unicode_range: Vec<(u32, u32)>,
+}
+
+impl FontFace {
+    pub fn new(family: &str) -> Self {
+        Self { family: family.to_owned(), src: Vec::new(), weight: None, style: None, display: None, unicode_range: Vec::new() }
+    }
+
+    pub fn src(mut self, source: FontSource) -> Self {
+        self.src.push(source);
+        self
+    }
+
+    pub fn weight(self, weight: u16) -> Self {
+        self.weight_range(weight, weight)
+    }
+
+    pub fn weight_range(mut self, from: u16, to: u16) -> Self {
+        self.weight = Some((from, to));
+        self
+    }
+
+    pub fn style(mut self, style: &str) -> Self {
+        self.style = Some(style.to_owned());
+        self
+    }
+
+    pub fn display(mut self, display: FontDisplay) -> Self {
+        self.display = Some(display);
+        self
+    }
+
+    pub fn unicode_range(mut self, from: u32, to: u32) -> Self {
+        self.unicode_range.push((from, to));
+        self
+    }
+
+    // @font-face { font-family: "Inter"; src: url("inter.woff2") format("woff2"); }
+    pub fn to_css(&self) -> String {
+        let mut declarations = format!("font-family: {}; ", quote(&self.family));
+        if !self.src.is_empty() {
+            let src = self.src.iter().map(FontSource::to_string).collect::<Vec<String>>();
+            declarations.push_str(&format!("src: {}; ", src.join(", ")));
+        }
+        match self.weight {
+            Some((from, to)) if from == to => declarations.push_str(&format!("font-weight: {}; ", from)),
+            Some((from, to)) => declarations.push_str(&format!("font-weight: {} {}; ", from, to)),
+            None => {},
+        }
+        if let Some(style) = &self.style {
+            declarations.push_str(&format!("font-style: {}; ", style));
+        }
+        if let Some(display) = self.display {
+            declarations.push_str(&format!("font-display: {}; ", display));
+        }
+        if !self.unicode_range.is_empty() {
+            let ranges = self.unicode_range.iter()
+                .map(|(from, to)| if from == to { format!("U+{:04X}", from) } else { format!("U+{:04X}-{:04X}", from, to) })
+                .collect::<Vec<String>>();
+            declarations.push_str(&format!("unicode-range: {}; ", ranges.join(", ")));
+        }
+
+        format!("@font-face {{ {}}}", declarations)
+    }
+
+    // inserts the rule into the registry (i.e. the <head> of the document) the same way Style::as_class does,
+    // a font face of the same family, weight and style replaces the one that was inserted before
+    pub fn append_to_head(&self, registry: impl StyleRegistry) {
+        self.try_append_to_head(registry).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_append_to_head(&self, mut registry: impl StyleRegistry) -> Result<(), Error> {
+        registry.insert(&self.key(), &self.to_css())
+    }
+
+    // the name the font face is inserted and added to a StyleSheet under, the same for every font face
+    // of the same family, weight and style (font-face-Inter-400-400-italic)
+    pub fn key(&self) -> String {
+        let weight = self.weight.map(|(from, to)| format!("-{}-{}", from, to)).unwrap_or_default();
+        let style = self.style.as_ref().map(|style| format!("-{}", style)).unwrap_or_default();
+        format!("font-face-{}{}{}", self.family, weight, style)
+    }
+}
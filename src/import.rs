@@ -0,0 +1,70 @@
+//! `@import` rules that load other stylesheets.
+
+use crate::registry::StyleRegistry;
+use crate::error::Error;
+use crate::font_face::quote;
+use crate::media::MediaQuery;
+use crate::supports::SupportsCondition;
+
+/// Another stylesheet, optionally put into a cascade layer and only loaded when the conditions hold,
+/// written as `@import url("theme.css") layer(theme) supports(display: grid) screen;`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    pub url: String,
+    // Some("") puts the stylesheet into an anonymous layer
+    pub layer: Option<String>,
+    pub supports: Option<SupportsCondition>,
+    pub media: Option<MediaQuery>,
+}
+
+impl Import {
+    pub fn new(url: &str) -> Self {
+        Self { url: url.to_owned(), layer: None, supports: None, media: None }
+    }
+
+    pub fn layer(mut self, name: &str) -> Self {
+        self.layer = Some(name.to_owned());
+        self
+    }
+
+    pub fn supports(mut self, condition: SupportsCondition) -> Self {
+        self.supports = Some(condition);
+        self
+    }
+
+    pub fn media(mut self, query: MediaQuery) -> Self {
+        self.media = Some(query);
+        self
+    }
+
+    // @import url("theme.css") layer(theme);
+    pub fn to_css(&self) -> String {
+        let mut rule = format!("@import url({})", quote(&self.url));
+        match self.layer.as_deref() {
+            Some("") => rule.push_str(" layer"),
+            Some(layer) => rule.push_str(&format!(" layer({})", layer)),
+            None => {},
+        }
+        match &self.supports {
+            // a single declaration doesn't need the parentheses of a condition
+            Some(SupportsCondition::Declaration(property, value)) => rule.push_str(&format!(" supports({}: {})", property, value)),
+            Some(supports) => rule.push_str(&format!(" supports({})", supports)),
+            None => {},
+        }
+        if let Some(media) = &self.media {
+            rule.push_str(&format!(" {}", media));
+        }
+        rule.push(';');
+        rule
+    }
+
+    // inserts the rule into the registry (i.e. the <head> of the document) the same way Style::as_class does,
+    // in a <style> element of its own since @import has to come first in a stylesheet
+    pub fn append_to_head(&self, registry: impl StyleRegistry) {
+        self.try_append_to_head(registry).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_append_to_head(&self, mut registry: impl StyleRegistry) -> Result<(), Error> {
+        registry.insert(&format!("import-{}", self.url), &self.to_css())
+    }
+}
@@ -0,0 +1,72 @@
+//! `@keyframes` built from instances of a style, one for every point of the animation.
+
+use crate::registry::StyleRegistry;
+use crate::error::Error;
+use crate::values::Percentage;
+use crate::Style;
+
+/// A named css animation. Every frame holds the declarations of a style at a point of the animation,
+/// so a style with `Option` fields can be used to only animate some properties.
+/// The name goes into the `animation` or `animation-name` property of the style that is animated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes {
+    name: String,
+    // the points of the animation with the inline css for them, in the order they were added
+    frames: Vec<(Percentage, String)>,
+}
+
+impl Keyframes {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_owned(), frames: Vec::new() }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // the style at the given point of the animation
+    pub fn frame<S: Style>(self, at: Percentage, style: &S) -> Self {
+        self.frame_css(at, &style.inline())
+    }
+
+    // inline css at the given point of the animation (opacity: 0; )
+    pub fn frame_css(mut self, at: Percentage, css: &str) -> Self {
+        // written like inline() writes declarations, each one followed by `; `
+        let mut css = css.trim().to_owned();
+        if !css.is_empty() {
+            if !css.ends_with(';') { css.push(';'); }
+            css.push(' ');
+        }
+        self.frames.push((at, css));
+        self
+    }
+
+    // the style at the start of the animation
+    pub fn from<S: Style>(self, style: &S) -> Self {
+        self.frame(Percentage(0.0), style)
+    }
+
+    // the style at the end of the animation
+    pub fn to<S: Style>(self, style: &S) -> Self {
+        self.frame(Percentage(100.0), style)
+    }
+
+    // @keyframes name { 0% { ... } 100% { ... } }
+    pub fn to_css(&self) -> String {
+        let frames = self.frames.iter()
+            .map(|(at, css)| format!("{} {{ {}}}", at, css))
+            .collect::<Vec<String>>();
+        format!("@keyframes {} {{ {} }}", self.name, frames.join(" "))
+    }
+
+    // inserts the keyframes into the registry (i.e. the <head> of the document) the same way Style::as_class does,
+    // returns the name of the animation
+    pub fn append_to_head(&self, registry: impl StyleRegistry) -> String {
+        self.try_append_to_head(registry).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_append_to_head(&self, mut registry: impl StyleRegistry) -> Result<String, Error> {
+        registry.insert(&format!("keyframes-{}", self.name), &self.to_css())?;
+        Ok(self.name.clone())
+    }
+}
@@ -1,3 +0,0 @@
-pub enum Pseudo {
-    Append,
-}
\ No newline at end of file
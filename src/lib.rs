@@ -1,13 +1,50 @@
 //! This crate can be used to convert rust structs into css, while maintaining
 //! the ability to access each field individually.
 
-//mod keywords;
-//use keywords::Pseudo;
 mod warning;
-use bevy_reflect::{Reflect, Struct, ReflectMut, List, Tuple};
-use std::{ num::ParseFloatError, cell::Ref };
-use web_sys::{ Document };
-use substring::*;
+mod error;
+mod numeric;
+mod blank;
+mod option;
+mod enums;
+mod naming;
+mod dom;
+mod split;
+pub mod tokenizer;
+pub mod selector;
+pub mod media;
+pub mod container;
+pub mod supports;
+pub mod keyframes;
+pub mod font_face;
+pub mod import;
+pub mod style_sheet;
+pub mod registry;
+pub mod values;
+pub use error::Error;
+pub use naming::ClassNaming;
+pub use selector::Selector;
+pub use media::MediaQuery;
+pub use container::ContainerQuery;
+pub use supports::SupportsCondition;
+pub use keyframes::Keyframes;
+pub use font_face::FontFace;
+pub use import::Import;
+pub use style_sheet::StyleSheet;
+pub use registry::{ StyleRegistry, MemoryRegistry };
+pub use blank::register_default;
+use error::offset_in;
+pub use rusty_css_derive::Style;
+use bevy_reflect::{Reflect, Struct, ReflectMut, List, Tuple, TupleStruct, Array, Map, Enum, TypeInfo, DynamicEnum, DynamicTuple, DynamicStruct, DynamicVariant, VariantInfo, VariantType};
+use std::any::TypeId;
+use std::num::ParseFloatError;
+
+// used by the code #[derive(Style)] generates
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::blank::{ Blank, BlankDefault, BlankFallback };
+    pub use crate::naming::{ Renames, RenamesStyle, RenamesFallback };
+}
 
 // add a smart way to extract the containing float value within a string 
 pub trait ExtractNums {
@@ -21,7 +58,7 @@ pub trait ExtractNums {
 
 impl ExtractNums for String {
     fn try_extract_nums(&self) -> Option<String> {
-        Some( self.chars().filter(|c| c.is_digit(10) || c == &'.' || c == &'-').collect() )
+        Some( self.chars().filter(|c| c.is_ascii_digit() || c == &'.' || c == &'-').collect() )
     }
 
     fn try_to_f64(&self) -> Result<f64, ParseFloatError> {
@@ -35,155 +72,598 @@ pub trait Style: Reflect + Struct {
     // constructor
     fn create() -> Self;
 
+    // the unit that is appended to the numbers in a field when rendering it and stripped from them when parsing it,
+    // #[derive(Style)] implements this for fields with a #[css(unit = "px")] attribute
+    fn field_unit(_field_name: &str) -> Option<&'static str> {
+        None
+    }
+
+    // the property name of a field that isn't just the field name in kebab case, #[derive(Style)] implements this
+    // for fields with a #[css(rename = "-webkit-line-clamp")] or #[css(custom)] attribute (brand_color -> --brand-color)
+    fn field_property(_field_name: &str) -> Option<&'static str> {
+        None
+    }
+
+    // the css property a field is written to and read from
+    fn property_name(field_name: &str) -> String {
+        Self::field_property(field_name).map(str::to_owned).unwrap_or_else(|| naming::field_css_name(field_name))
+    }
+
+    // the css name of a field of a nested struct, i.e. the function it's written as (skew_x -> skewX), #[derive(Style)]
+    // implements this for the types of its fields that derive Style too, so they can #[css(rename)] their fields
+    fn nested_property(_nested_type: TypeId, _field_name: &str) -> Option<String> {
+        None
+    }
+
+    // the css function a field of the nested struct of the given type is written to and read from
+    fn function_name(nested_type: TypeId, field_name: &str) -> String {
+        Self::nested_property(nested_type, field_name).unwrap_or_else(|| naming::field_css_name(field_name))
+    }
+
+    // a fresh value for the type T inside of an Option<T> or Vec<T> field, used to fill the field when it's still None
+    // and to add elements to the Vec, #[derive(Style)] implements this for every such field with a T that implements Default
+    fn blank_field_item(_field_name: &str) -> Option<Box<dyn Reflect>> {
+        None
+    }
+
+    // the selector of a field that holds a nested style, relative to the style around it (&:hover, & > .icon),
+    // #[derive(Style)] implements this for fields with a #[css(nest = "&:hover")], #[css(media = ...)], #[css(container = ...)]
+    // or #[css(supports = ...)] attribute, these fields aren't properties
+    fn field_nest(_field_name: &str) -> Option<&'static str> {
+        None
+    }
+
+    // the rules of the nested styles, with & standing for the selector of the style around them,
+    // #[derive(Style)] implements this by calling as_rule_string on every field with a #[css(nest = ...)] attribute
+    // and wrapping the rules of fields with a #[css(media = ...)], #[css(container = ...)] or #[css(supports = ...)] attribute
+    // in their conditions
+    fn nested_rules(&self, _selector: &str) -> Vec<String> {
+        Vec::new()
+    }
+
+    // the media query as_class_string wraps all rules of the style in,
+    // #[derive(Style)] implements this for structs with a #[css(media = "(min-width: 600px)")] attribute
+    fn media(&self) -> Option<MediaQuery> {
+        None
+    }
+
+    // the container query as_class_string wraps all rules of the style in,
+    // #[derive(Style)] implements this for structs with a #[css(container = ...)] attribute
+    fn container(&self) -> Option<ContainerQuery> {
+        None
+    }
+
+    // the feature query as_class_string wraps all rules of the style in,
+    // #[derive(Style)] implements this for structs with a #[css(supports = ...)] attribute
+    fn supports(&self) -> Option<SupportsCondition> {
+        None
+    }
+
+    // the selector that follows the class name in as_class_string (:hover, ::before, > li, etc.),
+    // #[derive(Style)] implements this for structs with a #[css(selector = ":hover")] attribute
+    fn selector(&self) -> Selector {
+        Selector::new()
+    }
+
+    // how as_class and add_as_pseudo_class name the class of the style,
+    // #[derive(Style)] implements this for structs with a #[css(naming = "hash")] or #[css(prefix = "app")] attribute
+    fn class_naming(&self) -> ClassNaming {
+        ClassNaming::ModulePath
+    }
+
+    // the path of the type that class names are made from (my_app::components::Button),
+    // #[derive(Style)] implements this with module_path!() and the name of the struct, since the output of
+    // std::any::type_name isn't guaranteed to stay the same between compiler versions
+    fn type_path(&self) -> String {
+        self.type_name().to_owned()
+    }
+
     // Example highly nested css and equivalent rust struct layout
     //                                                                            [, i    def(   lmn (  o,  p,  q  )  j  k  )   g    h )   o    p]
     //    prop:      abc(      i    def(   lmn (  o,  p,  q  )  j  k  )   g    h )   o    p    )   q   r, 
     //   field: Vec<( S { Vec<(S,   (S {  ( S  {  S,  S,  S  }, S, S  ),  S,   S },  S    S)>  },  S,  S)>
     
     fn set_string_reflect(string_reflect: &mut dyn Reflect, value: &str) {
+        if let Err(error) = Self::try_set_string_reflect(string_reflect, value, None) {
+            warning::css_parse_error::throw(&format!("{} ({})", value.trim(), error));
+        }
+    }
+
+    // sets String, bool, number and rusty_css::values fields, the unit is stripped from numbers before parsing them
+    fn try_set_string_reflect(string_reflect: &mut dyn Reflect, value: &str, unit: Option<&str>) -> Result<(), Error> {
         let new_value = value.trim();
-        *string_reflect.downcast_mut::<String>().unwrap() = new_value.into();
+        if let Some(string) = string_reflect.downcast_mut::<String>() {
+            *string = new_value.into();
+            Ok(())
+        } else if let Some(result) = numeric::set_from_css(string_reflect, value, unit) {
+            result
+        } else if let Some(css_value) = values::as_css_value_mut(string_reflect) {
+            css_value.set_from_css(value)
+        } else {
+            Err(Error::type_mismatch("String, bool, a number or a rusty_css::values type", string_reflect.type_name()))
+        }
     }
 
     fn set_reflect_caller(reflect_mut: ReflectMut, value: &str) {
+        if let Err(error) = Self::try_set_reflect_caller(reflect_mut, value, None) {
+            warning::css_parse_error::throw(&format!("{} ({})", value.trim(), error));
+        }
+    }
+
+    // the unit is passed down to every number within the value
+    fn try_set_reflect_caller(reflect_mut: ReflectMut, value: &str, unit: Option<&str>) -> Result<(), Error> {
         match reflect_mut {
+            ReflectMut::Struct(function_list) if values::is_function_list(function_list) => {
+                Self::try_set_function_list_reflect(function_list, value, unit)
+            },
+            ReflectMut::Struct(important) if values::is_important(important) => {
+                Self::try_set_important_reflect(important, value, unit)
+            },
+            ReflectMut::Struct(var) if values::is_var(var) => {
+                Self::try_set_var_reflect(var, value, unit)
+            },
             ReflectMut::Struct(struct_reflect) => {
-                Self::set_struct_reflect(struct_reflect, value);
+                Self::try_set_struct_reflect(struct_reflect, value, unit)
             },
             ReflectMut::Tuple(tuple_reflect) => {
-                Self::set_tuple_reflect(tuple_reflect, value);
+                Self::try_set_tuple_reflect(tuple_reflect, value, unit)
             },
             ReflectMut::List(list_reflect) => {
-                Self::set_list_reflect(list_reflect, value)
+                Self::try_set_list_reflect(list_reflect, value, unit, None)
+            },
+            ReflectMut::TupleStruct(tuple_struct_reflect) => {
+                Self::try_set_tuple_struct_reflect(tuple_struct_reflect, value, unit)
+            },
+            ReflectMut::Array(array_reflect) => {
+                Self::try_set_array_reflect(array_reflect, value, unit)
+            },
+            ReflectMut::Map(map_reflect) => {
+                Self::try_set_map_reflect(map_reflect, value, unit)
+            },
+            ReflectMut::Enum(option_reflect) if option::is_option(option_reflect) => {
+                Self::try_set_option_reflect(option_reflect, value, unit, None)
+            },
+            ReflectMut::Enum(enum_reflect) => {
+                Self::try_set_enum_reflect(enum_reflect, value, unit)
             },
-            ReflectMut::TupleStruct(_) => todo!(),
-            ReflectMut::Array(_) => todo!(),
-            ReflectMut::Map(_) => todo!(),
-            ReflectMut::Enum(_) => todo!(),
             ReflectMut::Value(string_reflect) => {
-                Self::set_string_reflect(string_reflect, value);
+                Self::try_set_string_reflect(string_reflect, value, unit)
             },
         }
     }
 
-    fn set_tuple_reflect(tuple_reflect: &mut dyn Tuple, value: &str) {
-        // separate string into vec of tuple values
-        let mut breaks = 0;
-        let max_breaks = tuple_reflect.field_len() - 1;
-        let mut paren_count = 0;
-        let formated_tuple_str = value.trim().chars().map(| c | {
-            if c == ' ' && paren_count == 0 && breaks < max_breaks{ 
-                breaks += 1;
-                return "<RustyCssTupleBreak>".to_string();
-            } else {
-                if c == '(' { paren_count += 1 }
-                if c == ')' { paren_count -= 1 }
-                return format!("{}", c);
+    // an Option that is Some is parsed into in place, one that is None is switched to Some(blank) with the value parsed into blank,
+    // if no blank is given, Strings, bools and numbers are created on the fly
+    fn try_set_option_reflect(option_reflect: &mut dyn Enum, value: &str, unit: Option<&str>, blank: Option<Box<dyn Reflect>>) -> Result<(), Error> {
+        if let Some(inner) = option_reflect.field_at_mut(0) {
+            return Self::try_set_reflect_caller(inner.reflect_mut(), value, unit);
+        }
+
+        let mut new_value = blank.or_else(|| option::blank_inner(option_reflect))
+            .ok_or_else(|| Error::type_mismatch("an Option of a String, bool, number or a type implementing Default", option_reflect.type_name()))?;
+        Self::try_set_reflect_caller(new_value.reflect_mut(), value, unit)?;
+
+        let mut some = DynamicTuple::default();
+        some.insert_boxed(new_value);
+        option_reflect.apply(&DynamicEnum::new(option_reflect.type_name().to_owned(), "Some".to_owned(), some));
+        Ok(())
+    }
+
+    fn set_enum_reflect(enum_reflect: &mut dyn Enum, value: &str) {
+        if let Err(error) = Self::try_set_enum_reflect(enum_reflect, value, None) {
+            warning::css_parse_error::throw(&format!("{} ({})", value.trim(), error));
+        }
+    }
+
+    // unit variants are read from css keywords (inline-flex), tuple and struct variants from css functions
+    // with one argument per field (rotate3d(a, b, c, d), or drop-shadow(a b c) for functions that separate them by whitespace)
+    fn try_set_enum_reflect(enum_reflect: &mut dyn Enum, value: &str, unit: Option<&str>) -> Result<(), Error> {
+        let trimmed = split::trim(value);
+        let (name, args) = match split::function(trimmed) {
+            Some(function) => (function.name, Some(function.args)),
+            None => (trimmed, None),
+        };
+
+        let variants = enums::variants(enum_reflect);
+        let variant = variants.iter()
+            .find(|variant| enums::css_name(variant) == name && matches!(variant, VariantInfo::Unit(_)) == args.is_none())
+            .ok_or_else(|| {
+                let expected = variants.iter().map(|variant| format!("`{}`", enums::css_name(variant))).collect::<Vec<String>>();
+                Error::parse(offset_in(value, trimmed), format!("expected one of {}, found `{}`", expected.join(", "), trimmed))
+            })?;
+
+        let new_values = match args {
+            Some(args) => enums::split_arguments(name, args).map_err(|e| e.offset_by(offset_in(value, args)))?,
+            None => Vec::new(),
+        };
+        let field_len = match variant {
+            VariantInfo::Struct(named) => named.field_len(),
+            VariantInfo::Tuple(tuple) => tuple.field_len(),
+            VariantInfo::Unit(_) => 0,
+        };
+        if new_values.len() != field_len {
+            let message = format!("expected {} arguments to `{}()`, found {}", field_len, name, new_values.len());
+            return Err(Error::parse(offset_in(value, trimmed), message));
+        }
+
+        // the fields of a variant that is set already are parsed into in place
+        if enum_reflect.variant_name() == variant.name() {
+            for (i, new_value) in new_values.into_iter().enumerate() {
+                let reflect_mut = enum_reflect.field_at_mut(i).unwrap().reflect_mut();
+                Self::try_set_reflect_caller(reflect_mut, new_value, unit).map_err(|e| e.offset_by(offset_in(value, new_value)))?;
             }
-        }).collect::<String>();
-        let new_values = formated_tuple_str.split("<RustyCssTupleBreak>").collect::<Vec<&str>>();
+            return Ok(());
+        }
+
+        // the fields of any other variant are created first, which only works for String, bool, number and rusty_css::values fields
+        let mut blank_fields = Vec::new();
+        for (i, new_value) in new_values.into_iter().enumerate() {
+            let field_type_name = match variant {
+                VariantInfo::Struct(named) => named.field_at(i).unwrap().type_name(),
+                VariantInfo::Tuple(tuple) => tuple.field_at(i).unwrap().type_name(),
+                VariantInfo::Unit(_) => unreachable!(),
+            };
+            let mut blank = blank::blank_value(field_type_name)
+                .ok_or_else(|| Error::type_mismatch("String, bool, number or rusty_css::values fields in the enum variant", field_type_name))?;
+            Self::try_set_reflect_caller(blank.reflect_mut(), new_value, unit).map_err(|e| e.offset_by(offset_in(value, new_value)))?;
+            blank_fields.push(blank);
+        }
+
+        let dynamic_variant = match variant {
+            VariantInfo::Struct(named) => {
+                let mut fields = DynamicStruct::default();
+                for (field, blank) in named.iter().zip(blank_fields) {
+                    fields.insert_boxed(field.name(), blank);
+                }
+                DynamicVariant::Struct(fields)
+            },
+            VariantInfo::Tuple(_) => {
+                let mut fields = DynamicTuple::default();
+                for blank in blank_fields {
+                    fields.insert_boxed(blank);
+                }
+                DynamicVariant::Tuple(fields)
+            },
+            VariantInfo::Unit(_) => DynamicVariant::Unit,
+        };
+        enum_reflect.apply(&DynamicEnum::new(enum_reflect.type_name().to_owned(), variant.name().to_owned(), dynamic_variant));
+
+        Ok(())
+    }
+
+    fn set_tuple_reflect(tuple_reflect: &mut dyn Tuple, value: &str) {
+        if let Err(error) = Self::try_set_tuple_reflect(tuple_reflect, value, None) {
+            warning::css_parse_error::throw(&format!("{} ({})", value.trim(), error));
+        }
+    }
 
-        for i in 0..tuple_reflect.field_len() {
+    fn try_set_tuple_reflect(tuple_reflect: &mut dyn Tuple, value: &str, unit: Option<&str>) -> Result<(), Error> {
+        // separate string into vec of tuple values at the whitespace outside of parentheses,
+        // the last tuple value gets whatever is left
+        let trimmed = split::trim(value);
+        let new_values = split::split_whitespace(trimmed, tuple_reflect.field_len());
+
+        // too few values, or any value at all for a container without elements
+        if new_values.len() != tuple_reflect.field_len() {
+            let message = format!("expected {} space separated values, found {}", tuple_reflect.field_len(), new_values.len());
+            let offset = new_values.get(tuple_reflect.field_len()).map_or(offset_in(value, trimmed) + trimmed.len(), |extra| offset_in(value, extra));
+            return Err(Error::parse(offset, message));
+        }
+
+        for (i, new_value) in new_values.into_iter().enumerate() {
             let reflect_mut = tuple_reflect.field_mut(i).unwrap().reflect_mut();
-            Self::set_reflect_caller(reflect_mut, new_values[i]);
+            Self::try_set_reflect_caller(reflect_mut, new_value, unit).map_err(|e| e.offset_by(offset_in(value, new_value)))?;
+        }
+
+        Ok(())
+    }
+
+    // a newtype (struct Px(String)) is read like the value it wraps, any other tuple struct like a tuple
+    fn try_set_tuple_struct_reflect(tuple_struct_reflect: &mut dyn TupleStruct, value: &str, unit: Option<&str>) -> Result<(), Error> {
+        if tuple_struct_reflect.field_len() == 1 {
+            return Self::try_set_reflect_caller(tuple_struct_reflect.field_mut(0).unwrap().reflect_mut(), value, unit);
         }
+
+        let trimmed = split::trim(value);
+        let new_values = split::split_whitespace(trimmed, tuple_struct_reflect.field_len());
+
+        // too few values, or any value at all for a container without elements
+        if new_values.len() != tuple_struct_reflect.field_len() {
+            let message = format!("expected {} space separated values, found {}", tuple_struct_reflect.field_len(), new_values.len());
+            let offset = new_values.get(tuple_struct_reflect.field_len()).map_or(offset_in(value, trimmed) + trimmed.len(), |extra| offset_in(value, extra));
+            return Err(Error::parse(offset, message));
+        }
+
+        for (i, new_value) in new_values.into_iter().enumerate() {
+            let reflect_mut = tuple_struct_reflect.field_mut(i).unwrap().reflect_mut();
+            Self::try_set_reflect_caller(reflect_mut, new_value, unit).map_err(|e| e.offset_by(offset_in(value, new_value)))?;
+        }
+
+        Ok(())
+    }
+
+    // arrays are read like tuples, an array of four values also takes one to three values
+    // the way the box shorthands of css do (margin: 1px 2px is margin: 1px 2px 1px 2px)
+    fn try_set_array_reflect(array_reflect: &mut dyn Array, value: &str, unit: Option<&str>) -> Result<(), Error> {
+        let trimmed = split::trim(value);
+        let mut new_values = split::split_whitespace(trimmed, array_reflect.len());
+
+        if array_reflect.len() == 4 && (1..4).contains(&new_values.len()) {
+            // top, right, bottom, left
+            let top = new_values[0];
+            let right = new_values.get(1).copied().unwrap_or(top);
+            let bottom = new_values.get(2).copied().unwrap_or(top);
+            new_values = vec![top, right, bottom, right];
+        }
+
+        // too few values, or any value at all for a container without elements
+        if new_values.len() != array_reflect.len() {
+            let message = format!("expected {} space separated values, found {}", array_reflect.len(), new_values.len());
+            let offset = new_values.get(array_reflect.len()).map_or(offset_in(value, trimmed) + trimmed.len(), |extra| offset_in(value, extra));
+            return Err(Error::parse(offset, message));
+        }
+
+        for (i, new_value) in new_values.into_iter().enumerate() {
+            let reflect_mut = array_reflect.get_mut(i).unwrap().reflect_mut();
+            Self::try_set_reflect_caller(reflect_mut, new_value, unit).map_err(|e| e.offset_by(offset_in(value, new_value)))?;
+        }
+
+        Ok(())
+    }
+
+    // every css function is an entry of the map (a(1) b(2) is {"a": 1, "b": 2}),
+    // entries that aren't in the css are left as they are
+    fn try_set_map_reflect(map_reflect: &mut dyn Map, value: &str, unit: Option<&str>) -> Result<(), Error> {
+        for function in split::functions(value)? {
+            Self::try_set_map_entry(map_reflect, function.name, function.args, unit).map_err(|e| e.offset_by(offset_in(value, function.args)))?;
+        }
+
+        Ok(())
+    }
+
+    // parses the value into the entry of a map with String keys,
+    // a missing entry is made from a fresh String, bool, number or rusty_css::values type
+    fn try_set_map_entry(map_reflect: &mut dyn Map, key: &str, value: &str, unit: Option<&str>) -> Result<(), Error> {
+        let (key_type_name, value_type_name) = match map_reflect.get_type_info() {
+            TypeInfo::Map(info) => (info.key_type_name(), info.value_type_name()),
+            _ => return Err(Error::type_mismatch("HashMap", map_reflect.type_name())),
+        };
+        if key_type_name != std::any::type_name::<String>() {
+            return Err(Error::type_mismatch("a HashMap with String keys", map_reflect.type_name()));
+        }
+
+        let key = key.to_owned();
+        if let Some(entry) = map_reflect.get_mut(&key) {
+            return Self::try_set_reflect_caller(entry.reflect_mut(), value, unit);
+        }
+
+        let mut entry = blank::blank_value(value_type_name)
+            .ok_or_else(|| Error::type_mismatch("a HashMap of Strings, bools, numbers or rusty_css::values types", map_reflect.type_name()))?;
+        Self::try_set_reflect_caller(entry.reflect_mut(), value, unit)?;
+        map_reflect.insert_boxed(Box::new(key), entry);
+
+        Ok(())
     }
 
     fn set_list_reflect(list_reflect: &mut dyn List, value: &str) {
-        // separate string into vec of string
-        let mut paren_count = 0;
-        let formated_vec_str = value.chars().map(| c | {
-            if c == '(' { paren_count += 1 }
-            if c == ')' { paren_count -= 1 }
-            if c == ',' && paren_count == 0 { 
-                return "<RustyCssVecBreak>".to_string();
-            } else {
-                return format!("{}", c);
-            }
-        }).collect::<String>();
-        let new_values = formated_vec_str.split("<RustyCssVecBreak>").collect::<Vec<&str>>();
+        if let Err(error) = Self::try_set_list_reflect(list_reflect, value, None, None) {
+            warning::css_parse_error::throw(&format!("{} ({})", value.trim(), error));
+        }
+    }
+
+    // the list gets one element per comma separated value, new elements are made from the item type: from the blank
+    // if one is given, else as a fresh String, bool, number or rusty_css::values type or the default of a registered type
+    fn try_set_list_reflect(list_reflect: &mut dyn List, value: &str, unit: Option<&str>, blank: Option<Box<dyn Reflect>>) -> Result<(), Error> {
+        // separate string into vec of string at the commas outside of parentheses
+        let new_values = split::split_commas(value);
 
         // Pop elements according to given css string
-        if list_reflect.len() > new_values.len() {
-            for _i in new_values.len()..list_reflect.len() {
-                list_reflect.pop();
+        while list_reflect.len() > new_values.len() {
+            list_reflect.pop();
+        }
+
+        // push elements according to given css string
+        if list_reflect.len() < new_values.len() {
+            let (item_type_name, item_type_id) = match list_reflect.get_type_info() {
+                TypeInfo::List(info) => (info.item_type_name(), Some(info.item_type_id())),
+                _ => (list_reflect.type_name(), None),
+            };
+            let blank = blank
+                .or_else(|| blank::blank_value(item_type_name))
+                .or_else(|| blank::registered_default(item_type_id?))
+                // items without a default at all are built with every field blank, the types of the fields are taken from the first element
+                .or_else(|| blank::blank_like(list_reflect.get(0)?))
+                .ok_or_else(|| Error::type_mismatch("a Vec of Strings, bools, numbers or types implementing Default (registered with register_default() in nested structs)", list_reflect.type_name()))?;
+            while list_reflect.len() < new_values.len() {
+                list_reflect.push(blank.clone_value());
             }
         }
 
         // iterate list elements and call type appropriate function
-        for i in 0..list_reflect.len() {
+        for (i, new_value) in new_values.into_iter().enumerate() {
             let reflect_mut = list_reflect.get_mut(i).unwrap().reflect_mut();
-            Self::set_reflect_caller(reflect_mut, new_values[i]);
+            Self::try_set_reflect_caller(reflect_mut, new_value, unit).map_err(|e| e.offset_by(offset_in(value, new_value)))?;
         }
+
+        Ok(())
     }
-    
+
+    // the functions are separated by spaces and each one is parsed into an element of the list,
+    // elements that are missing are cloned from the blank one of the list first
+    fn try_set_function_list_reflect(function_list: &mut dyn Struct, value: &str, unit: Option<&str>) -> Result<(), Error> {
+        let new_values = match value.trim() {
+            "none" => Vec::new(),
+            _ => split::split_whitespace(value, usize::MAX),
+        };
+
+        let blank = function_list.field("blank").unwrap().clone_value();
+        let ReflectMut::List(functions) = function_list.field_mut("functions").unwrap().reflect_mut() else {
+            return Err(Error::type_mismatch("Vec", function_list.type_name()));
+        };
+        while functions.len() > new_values.len() {
+            functions.pop();
+        }
+        while functions.len() < new_values.len() {
+            functions.push(blank.clone_value());
+        }
+
+        for (i, new_value) in new_values.into_iter().enumerate() {
+            let reflect_mut = functions.get_mut(i).unwrap().reflect_mut();
+            Self::try_set_reflect_caller(reflect_mut, new_value, unit).map_err(|e| e.offset_by(offset_in(value, new_value)))?;
+        }
+
+        Ok(())
+    }
+
+    // the `!important` flag is taken off the end of the value and the rest is parsed into the wrapped value
+    fn try_set_important_reflect(important: &mut dyn Struct, value: &str, unit: Option<&str>) -> Result<(), Error> {
+        let (new_value, flag) = split::split_important(value);
+        let reflect_mut = important.field_mut("value").unwrap().reflect_mut();
+        Self::try_set_reflect_caller(reflect_mut, new_value, unit).map_err(|e| e.offset_by(offset_in(value, new_value)))?;
+        important.field_mut("important").unwrap().apply(&flag);
+        Ok(())
+    }
+
+    // reads var(--name) and var(--name, fallback), everything after the first comma is the fallback
+    fn try_set_var_reflect(var: &mut dyn Struct, value: &str, unit: Option<&str>) -> Result<(), Error> {
+        let trimmed = split::trim(value);
+        let function = split::function(trimmed)
+            .filter(|function| function.name.eq_ignore_ascii_case("var"))
+            .ok_or_else(|| Error::parse(offset_in(value, trimmed), format!("expected `var(--name)`, found `{}`", trimmed)))?;
+
+        let args = split::split_commas(function.args);
+        let name = args.first().copied().unwrap_or(function.args);
+        let custom_name = name.strip_prefix("--").filter(|custom_name| !custom_name.is_empty()).ok_or_else(|| {
+            Error::parse(offset_in(value, name), format!("expected a custom property like `--name`, found `{}`", name))
+        })?;
+        var.field_mut("name").unwrap().apply(&custom_name.to_owned());
+
+        let ReflectMut::Enum(fallback) = var.field_mut("fallback").unwrap().reflect_mut() else {
+            return Err(Error::type_mismatch("Option", var.type_name()));
+        };
+        match args.get(1) {
+            Some(first) => {
+                let rest = split::trim(&function.args[offset_in(function.args, first)..]);
+                Self::try_set_option_reflect(fallback, rest, unit, None).map_err(|e| e.offset_by(offset_in(value, rest)))
+            },
+            None => {
+                fallback.apply(&DynamicEnum::new(fallback.type_name().to_owned(), "None".to_owned(), DynamicVariant::Unit));
+                Ok(())
+            },
+        }
+    }
+
     fn set_struct_reflect(struct_reflect: &mut dyn Struct, value: &str) {
+        if let Err(error) = Self::try_set_struct_reflect(struct_reflect, value, None) {
+            warning::css_parse_error::throw(&format!("{} ({})", value.trim(), error));
+        }
+    }
+
+    // css functions that aren't represented in the struct are skipped,
+    // so neither the order nor the number of functions in the css string matter
+    fn try_set_struct_reflect(struct_reflect: &mut dyn Struct, value: &str, unit: Option<&str>) -> Result<(), Error> {
         //value = abc(i def(lmn(o,p,q) j k) g h, i def(lmn() j k) g h) l m,    abc(i def(lmn(o,p,q) j k) g h, i def(lmn(o,p,q) j k) g h) l m
-        let func_rest = value.split_once("(").unwrap(); //  "ab-c" "...)"
-        let func_name = func_rest.0.trim().replace("-", "_");  //   "ab_c" "...)"
-        let field = struct_reflect.field_mut(&func_name);
-
-        // extract argument to css function
-        let mut paren_count = 1;
-        let mut end_of_argument = 0;
-        func_rest.1.chars().for_each(| c | {
-            if paren_count == 0 { return; }
-            if c == '(' { paren_count += 1 }
-            if c == ')' { paren_count -= 1 }
-            end_of_argument += 1;
-        });
-        let args_rest = func_rest.1.split_at(end_of_argument);
-        let args = args_rest.0.strip_suffix(")").unwrap();
-
-        // handle css that isnt represented in the struct
-        if field.is_none() { println!("no field named {}", func_name); return; }
-        
-        // call type appropriate function for the field
-        let reflect_mut = field.unwrap().reflect_mut();
-        Self::set_reflect_caller(reflect_mut, args);
+        for function in split::functions(value)? {
+            let nested_type = struct_reflect.as_any().type_id();
+            let Some(field_name) = naming::find_field(struct_reflect, function.name, |name| Self::function_name(nested_type, name)).map(str::to_owned) else {
+                continue;
+            };
+
+            // call type appropriate function for the field
+            if let Some(field) = struct_reflect.field_mut(&field_name) {
+                Self::try_set_reflect_caller(field.reflect_mut(), function.args, unit).map_err(|e| e.offset_by(offset_in(value, function.args)))?;
+            }
+        }
 
-        // recurse if theres "func(args)" left (multiple fields in a struct field of type struct)
-        let rest = args_rest.1.trim();
-        if !rest.is_empty() { Self::set_struct_reflect(struct_reflect, rest) }
+        Ok(())
     }
 
-    // mutates a given objects fields to match a given inline css string
+    // mutates a given objects fields to match a given inline css string,
+    // declarations that can't be read are skipped with a warning
     fn set_from_inline_string(&mut self, style: String) where Self: Sized {
-        let prop_value = style.split(";"); //[" a: b", " c: d"]
-        prop_value.into_iter().for_each(|pv| {
-            if pv.trim().is_empty() { return; } // end of the css string
-
-            let prop_value = pv.split_once(":").unwrap();
-            let field_name = prop_value.0.replace("-", "_").replace(" ", "").replace("\n", "");
-
-            // if the prop name corresponds to a field name
-            if let Some(field) = self.field_mut(&field_name) {
-                // call the type appropriate function for the field
-                let reflect_mut = field.reflect_mut();
-                Self::set_reflect_caller(reflect_mut, prop_value.1);
+        for declaration in split::split_declarations(&style) {
+            match self.try_set_declaration(declaration) {
+                Ok(()) | Err(Error::UnknownProperty(_)) => {},
+                Err(error) => warning::css_parse_error::throw(&format!("{} ({})", declaration.trim(), error)),
             }
-        });
+        }
+    }
+
+    // mutates a given objects fields to match a given inline css string,
+    // stops at the first declaration that can't be read
+    fn try_set_from_inline_string(&mut self, style: &str) -> Result<(), Error> where Self: Sized {
+        for declaration in split::split_declarations(style) {
+            self.try_set_declaration(declaration).map_err(|e| e.offset_by(offset_in(style, declaration)))?;
+        }
+
+        Ok(())
+    }
+
+    // sets the field corresponding to a single "property: value" pair
+    fn try_set_declaration(&mut self, declaration: &str) -> Result<(), Error> where Self: Sized {
+        if split::trim(declaration).is_empty() { return Ok(()); } // end of the css string
+
+        let (property, value) = split::split_property(declaration).ok_or_else(|| {
+            let trimmed = split::trim(declaration);
+            Error::parse(offset_in(declaration, trimmed), format!("expected `property: value`, found `{}`", trimmed))
+        })?;
+
+        // if the prop name corresponds to a field name
+        let field_name = naming::find_field(self, property, Self::property_name)
+            .filter(|field_name| Self::field_nest(field_name).is_none())
+            .map(str::to_owned);
+        let Some(field_name) = field_name else {
+            return self.try_set_extra_property(property, value).map_err(|e| e.offset_by(offset_in(declaration, value)));
+        };
+        let unit = Self::field_unit(&field_name);
+        let field = self.field_mut(&field_name).unwrap();
+
+        // call the type appropriate function for the field
+        let result = match field.reflect_mut() {
+            ReflectMut::Enum(option_reflect) if option::is_option(option_reflect) => {
+                Self::try_set_option_reflect(option_reflect, value, unit, Self::blank_field_item(&field_name))
+            },
+            ReflectMut::List(list_reflect) => {
+                Self::try_set_list_reflect(list_reflect, value, unit, Self::blank_field_item(&field_name))
+            },
+            reflect_mut => Self::try_set_reflect_caller(reflect_mut, value, unit),
+        };
+        result.map_err(|e| e.offset_by(offset_in(declaration, value)))
+    }
+
+    // properties without a field of their own go into the first HashMap field of the struct, under their css name
+    fn try_set_extra_property(&mut self, property: &str, value: &str) -> Result<(), Error> where Self: Sized {
+        for i in 0..self.field_len() {
+            if let ReflectMut::Map(map_reflect) = self.field_at_mut(i).unwrap().reflect_mut() {
+                return Self::try_set_map_entry(map_reflect, property, value, None);
+            }
+        }
+
+        Err(Error::UnknownProperty(property.to_owned()))
     }
 
     // creates a string in the form of an inline style css string
     fn inline(&self) -> String where Self: Sized {
         let mut style_string = "".to_owned();
+        let mut has_extra_properties = false;
 
         //iterate over fields of the component
         for (i, value_reflect) in self.iter_fields().enumerate() {
 
             //get the name of the structs field as a String
-            let mut property_name = self.name_at(i).unwrap().to_owned();
-            
-            //++++++++++++++++++++++++++++++++++++++++++++
-            // horrendous way of implementing reserved keywords
-            // but I'm tired now so this will have to do
-            //++++++++++++++++++++++++++++++++++++++++++++
-            if property_name != "append" {
-                property_name = property_name.replace("_", "-");
+            let field_name = self.name_at(i).unwrap();
+
+            if let (bevy_reflect::ReflectRef::Map(map), false) = (value_reflect.reflect_ref(), has_extra_properties) {
+                // the entries of the first HashMap field are properties of their own
+                has_extra_properties = true;
+                for (property, value) in Self::map_entries(map, None) {
+                    style_string.push_str( &format!("{property}: {value}; ", property = property, value = value) );
+                }
+            } else if !option::is_none(value_reflect) && Self::field_nest(field_name).is_none() {
+                let property_name = Self::property_name(field_name);
 
                 //initialize the value to be given for the property in property_name (i.e. width, height, transform, etc) 
-                let value = Self::create_value_string(value_reflect);
+                let value = Self::create_value_string_with_unit(value_reflect, Self::field_unit(field_name));
 
                 style_string.push_str( &format!("{property}: {value}; ", property = property_name, value = value) );
             }
@@ -192,57 +672,147 @@ pub trait Style: Reflect + Struct {
         style_string
     }
 
+    // the keys and rendered values of a map, sorted by key so the css doesn't change between renders
+    fn map_entries(map: &dyn Map, unit: Option<&str>) -> Vec<(String, String)> {
+        let mut entries = map.iter()
+            .map(|(key, value)| {
+                let key = key.downcast_ref::<String>().cloned().unwrap_or_else(|| Self::create_value_string(key));
+                (key, Self::create_value_string_with_unit(value, unit))
+            })
+            .collect::<Vec<(String, String)>>();
+        entries.sort();
+        entries
+    }
+
     // creates a string for the values behind the css property
     fn create_value_string(reflect: &dyn Reflect) -> String {
+        Self::create_value_string_with_unit(reflect, None)
+    }
+
+    // creates a string for the values behind the css property, appending the unit to every number
+    fn create_value_string_with_unit(reflect: &dyn Reflect, unit: Option<&str>) -> String {
         let mut value = "".to_owned();
 
         match &reflect.reflect_ref() {
             
+            //the functions of a FunctionList are separated by spaces instead of being named by the fields
+            bevy_reflect::ReflectRef::Struct(function_list) if values::is_function_list(*function_list) => {
+                if let bevy_reflect::ReflectRef::List(functions) = function_list.field("functions").unwrap().reflect_ref() {
+                    let functions = functions.iter()
+                        .map(|function| Self::create_value_string_with_unit(function, unit))
+                        .collect::<Vec<String>>();
+                    if functions.is_empty() {
+                        value.push_str("none");
+                    } else {
+                        value.push_str( &functions.join(" ") );
+                    }
+                }
+            },
+            //the flag of an Important value is written after the value
+            bevy_reflect::ReflectRef::Struct(important) if values::is_important(*important) => {
+                value.push_str( &Self::create_value_string_with_unit(important.field("value").unwrap(), unit) );
+                if important.field("important").unwrap().downcast_ref::<bool>() == Some(&true) {
+                    value.push_str(" !important");
+                }
+            },
+            //a Var is written as a var() function with the fallback as its second argument
+            bevy_reflect::ReflectRef::Struct(var) if values::is_var(*var) => {
+                let name = var.field("name").unwrap().downcast_ref::<String>().cloned().unwrap_or_default();
+                match var.field("fallback").unwrap() {
+                    fallback if option::is_none(fallback) => value.push_str( &format!("var(--{})", name) ),
+                    fallback => value.push_str( &format!("var(--{}, {})", name, Self::create_value_string_with_unit(fallback, unit)) ),
+                }
+            },
             //check if the field is a nested struct (i.e. Transform, etc.)
             bevy_reflect::ReflectRef::Struct(fields) => {
                 
                 //loop over the nested struct                    
                 for (i, value_reflect) in fields.iter_fields().enumerate() {
+                    if option::is_none(value_reflect) { continue; }
+
                     //function names like skewX, skewY, etc.
-                    let function_name = fields.name_at(i).unwrap().replace("_", "-");
-                    let function_param = Self::create_value_string(value_reflect);
+                    let function_name = Self::function_name(fields.as_any().type_id(), fields.name_at(i).unwrap());
+                    let function_param = Self::create_value_string_with_unit(value_reflect, unit);
                     let value_string = format!(" {function}({parameter})", function = &function_name, parameter = &function_param);
                     value.push_str(&value_string);
                 }
             },
             bevy_reflect::ReflectRef::List(arr) => {
                 //loop over the vector                    
-                for (i, value_reflect) in arr.iter().enumerate() {
+                for (i, value_reflect) in arr.iter().filter(|r| !option::is_none(*r)).enumerate() {
                     // dont set the leading comma if its the first element
                     let mut comma = "".to_string();
                     if i != 0 {
                         comma = ", ".to_string();
                     }
                     
-                    let value_string = format!("{comma}{value}", value = Self::create_value_string(value_reflect), comma = comma);
+                    let value_string = format!("{comma}{value}", value = Self::create_value_string_with_unit(value_reflect, unit), comma = comma);
                     value.push_str(&value_string);
                 }
             },
             bevy_reflect::ReflectRef::Tuple(tuple) => {
                 //loop over the Tuple                    
-                for (i, value_reflect) in tuple.iter_fields().enumerate() {
+                for (i, value_reflect) in tuple.iter_fields().filter(|r| !option::is_none(*r)).enumerate() {
                     // dont set the leading comma if its the first element
                     let mut space = "".to_string();
                     if i != 0 {
                         space = " ".to_string();
                     }
                     
-                    let value_string = format!("{space}{value}", value = Self::create_value_string(value_reflect), space = space);
+                    let value_string = format!("{space}{value}", value = Self::create_value_string_with_unit(value_reflect, unit), space = space);
                     value.push_str(&value_string);
                 }
             }
+            //a newtype is rendered as the value it wraps, other tuple structs and arrays like tuples
+            bevy_reflect::ReflectRef::TupleStruct(tuple_struct) => {
+                let values = tuple_struct.iter_fields()
+                    .filter(|r| !option::is_none(*r))
+                    .map(|value_reflect| Self::create_value_string_with_unit(value_reflect, unit))
+                    .collect::<Vec<String>>();
+                value.push_str( &values.join(" ") );
+            }
+            bevy_reflect::ReflectRef::Array(array) => {
+                let values = array.iter()
+                    .filter(|r| !option::is_none(*r))
+                    .map(|value_reflect| Self::create_value_string_with_unit(value_reflect, unit))
+                    .collect::<Vec<String>>();
+                value.push_str( &values.join(" ") );
+            }
+            //the entries of a map are rendered like the fields of a struct, the keys being the function names
+            bevy_reflect::ReflectRef::Map(map) => {
+                for (key, param) in Self::map_entries(*map, unit) {
+                    value.push_str( &format!(" {function}({parameter})", function = key, parameter = param) );
+                }
+            }
             //check if the field is a value type (i.e. String, i32, f32, etc.)
             bevy_reflect::ReflectRef::Value(v) => {
-                let value_string = v.downcast_ref::<String>().unwrap();
-                value.push_str( value_string );
+                if let Some(value_string) = v.downcast_ref::<String>() {
+                    value.push_str( value_string );
+                } else if let Some(value_string) = numeric::to_css(*v, unit) {
+                    value.push_str( &value_string );
+                } else if let Some(css_value) = values::as_css_value(*v) {
+                    value.push_str( &css_value.to_css() );
+                } else {
+                    warning::rust_parse_error::throw(&format!("{:?}", reflect.get_type_info()));
+                }
             }
-            _ => {
-                warning::rust_parse_error::throw(&format!("{:?}", reflect.get_type_info()));
+            //an Option that is Some is rendered as the value it holds, None as nothing
+            bevy_reflect::ReflectRef::Enum(option_reflect) if option::is_option(*option_reflect) => {
+                if let Some(inner) = option_reflect.field_at(0) {
+                    value.push_str( &Self::create_value_string_with_unit(inner, unit) );
+                }
+            }
+            //unit variants are css keywords, all others css functions with the fields as arguments
+            bevy_reflect::ReflectRef::Enum(enum_reflect) => {
+                if enum_reflect.variant_type() == VariantType::Unit {
+                    value.push_str( &enums::keyword_name(enum_reflect.variant_name()) );
+                } else {
+                    let args = enum_reflect.iter_fields()
+                        .map(|field| Self::create_value_string_with_unit(field.value(), unit))
+                        .collect::<Vec<String>>();
+                    let function_name = enums::function_name(enum_reflect.variant_name());
+                    value.push_str( &format!("{}({})", function_name, args.join(enums::argument_separator(&function_name))) );
+                }
             }
         }
 
@@ -250,74 +820,100 @@ pub trait Style: Reflect + Struct {
     }
 
 
-    fn as_class_string(&self, class_name: &String) -> Result<String, &'static str> where Self: Sized {
+    fn as_class_string(&self, class_name: &str) -> Result<String, Error> where Self: Sized {
+
+        // the selector follows the class name (i.e. .struct_ident:pseudo_class)
+        let mut rules = self.as_rule_string(&format!(".{}{}", class_name, self.selector()));
+
+        // the conditions are nested with @media outermost (@media { @container { @supports { rules } } })
+        if let Some(supports) = self.supports() {
+            rules = supports.wrap(&rules);
+        }
+        if let Some(container) = self.container() {
+            rules = container.wrap(&rules);
+        }
+        if let Some(media) = self.media() {
+            rules = media.wrap(&rules);
+        }
+        Ok(rules)
+    }
 
-        let mut class_name_appended = class_name.clone();
-        // append pseudo-class name to the class name (i.e. .struct_ident:pseudo_class)
-        let append = self.field("append");
-        if !append.is_none() {
-            class_name_appended.push_str(
-                append.unwrap().downcast_ref::<String>().unwrap()
-            );
+    // the style as a css rule for the given selector, followed by the rules nested in it
+    fn as_rule_string(&self, selector: &str) -> String where Self: Sized {
+        let mut rules = format!("{} {{ {}}}", selector, self.inline());
+        for nested_rule in self.nested_rules(selector) {
+            rules.push(' ');
+            rules.push_str(&nested_rule);
         }
-           
-        Ok( format!(".{} {{ {}}}", class_name_appended, self.inline()) )
+        rules
     }
 
-    fn as_class(&self, document: &Document) -> Result<String, &'static str> where Self: Sized {
+    // puts the style as a class into the registry, which is the <head> of a web_sys::Document
+    // or a MemoryRegistry when there is no document (i.e. on the server)
+    fn as_class(&self, registry: impl StyleRegistry) -> Result<String, Error> where Self: Sized {
         
         // get struct name as class name
-        let class_name = self.get_struct_name().unwrap();
+        let class_name = self.get_struct_name()?;
 
         // create the string that is supposed to be inserted into the head as class
-        let class_string = self.as_class_string(&class_name).expect("Class string could not be created");
+        let class_string = self.as_class_string(&class_name)?;
 
         // insert the class
-        self.append_to_head(&document, &class_name, &class_string);
+        self.try_append_to_head(registry, &class_name, &class_string)?;
         
         // return just the class name
         Ok(class_name)
     }
 
-    fn append_to_head(&self, document: &Document, class_name: &String, class_string: &String) where Self: Sized {
-        let head = document.head().expect("No <head> element found in the document");
-        let new_style_element = document.create_element("style").expect("couldn't create <style> element in this document");
-        let style_id = format!("rusty-css-{}", class_name.replace(":", "_"));
-        new_style_element.set_attribute("id", &style_id ).expect("couldn't set attribute of internally created style tag");
-        new_style_element.set_text_content(Some(&class_string));
+    // puts the style into the registry as a class that is named after its css (i.e. Button-3f2a91c0),
+    // so instances with different values get classes of their own and instances with the same values share one
+    fn as_unique_class(&self, registry: impl StyleRegistry) -> Result<String, Error> where Self: Sized {
+        let class_name = self.unique_class_name()?;
+        let class_string = self.as_class_string(&class_name)?;
+        self.try_append_to_head(registry, &class_name, &class_string)?;
+        Ok(class_name)
+    }
 
-        if let Some(existent_style) = head.query_selector(&format!("#{}", style_id) ).expect("an error occured while trying to fetch the element with id `rusty-css` in head") {
-            head.remove_child(&existent_style).expect("couldn't remove child element with id `rusty-css` in head");
-        }
+    // the class name followed by a hash of the rules of the style, which only changes when the css does
+    fn unique_class_name(&self) -> Result<String, Error> where Self: Sized {
+        let class_name = self.get_struct_name()?;
+        let class_string = self.as_class_string(&class_name)?;
+        Ok(format!("{}-{:08x}", class_name, naming::hash(&class_string)))
+    }
 
-        head.append_child(&new_style_element).expect("couldn't append internally created `style` element with id `rusty-css` to head");
+    fn append_to_head(&self, registry: impl StyleRegistry, class_name: &str, class_string: &str) where Self: Sized {
+        self.try_append_to_head(registry, class_name, class_string).unwrap_or_else(|error| panic!("{}", error));
     }
 
-    fn add_as_pseudo_class(&self, document: &Document) where Self: Sized {
+    fn try_append_to_head(&self, mut registry: impl StyleRegistry, class_name: &str, class_string: &str) -> Result<(), Error> where Self: Sized {
+        registry.insert(class_name, class_string)
+    }
+
+    fn add_as_pseudo_class(&self, registry: impl StyleRegistry) where Self: Sized {
+        self.try_add_as_pseudo_class(registry).unwrap_or_else(|error| panic!("{}", error));
+    }
+
+    fn try_add_as_pseudo_class(&self, registry: impl StyleRegistry) -> Result<(), Error> where Self: Sized {
         
-        let mut class_name = self.get_struct_name().unwrap();
-        class_name = class_name.replace("_", ":");
+        // the part of the type's name after the first _ is the pseudo-class (Button_hover is Button:hover)
+        let (type_name, pseudo_class) = naming::split_pseudo_class(&self.type_path());
+        let class_name = format!("{}{}", self.class_naming().class_name(&type_name), pseudo_class);
 
-        let class_string = self.as_class_string(&class_name).expect("Class string could not be created");
+        let class_string = self.as_class_string(&class_name)?;
 
-        self.append_to_head(document, &class_name, &class_string);
+        self.try_append_to_head(registry, &class_name, &class_string)
     }
 
-    fn get_struct_name(&self) -> Result<String, &'static str> where Self: Sized {
-        
-        // cuts off the "arbitrary_caller_name::" from "arbitrary_caller_name::StructIdent and returns just the StructIdent"
-        let class_name_pos = self.type_name().rfind("::").expect("(Internal Error) couldn't find position of `::` in type_name");
-        let class_name_slice = self.type_name().substring(class_name_pos + 2, self.type_name().len());
-        if class_name_slice == "" { return Err("(Internal Error) couldn't strip arbitrary_caller_name:: prefix"); }
-        
-        Ok(class_name_slice.to_owned())
+    // the class name of the style, the name of its type as given by class_naming
+    fn get_struct_name(&self) -> Result<String, Error> where Self: Sized {
+        Ok(self.class_naming().class_name(&self.type_path()))
     }
 
     fn debug(self) -> Self where Self: Sized {
         
         wasm_logger::init(wasm_logger::Config::default());
 
-        for (_i, value_reflect) in self.iter_fields().enumerate() {
+        for value_reflect in self.iter_fields() {
             log::info!("{:?}", value_reflect.get_type_info());
         }
 
@@ -0,0 +1,152 @@
+//! Media queries that rules are wrapped in, like `@media screen and (min-width: 600px) { ... }`.
+
+use crate::values::Length;
+use std::fmt;
+
+// the kind of device a media query applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaType {
+    All,
+    Screen,
+    Print,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReducedMotion {
+    NoPreference,
+    Reduce,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Portrait,
+    Landscape,
+}
+
+// a condition of a media query, every one of them has to be met
+#[derive(Debug, Clone, PartialEq)]
+pub enum MediaFeature {
+    MinWidth(Length),
+    MaxWidth(Length),
+    MinHeight(Length),
+    MaxHeight(Length),
+    PrefersColorScheme(ColorScheme),
+    PrefersReducedMotion(ReducedMotion),
+    Orientation(Orientation),
+    // any other condition, written as-is (i.e. `(hover: hover)`)
+    Raw(String),
+}
+
+impl fmt::Display for MediaFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MediaFeature::MinWidth(width) => write!(f, "(min-width: {})", width),
+            MediaFeature::MaxWidth(width) => write!(f, "(max-width: {})", width),
+            MediaFeature::MinHeight(height) => write!(f, "(min-height: {})", height),
+            MediaFeature::MaxHeight(height) => write!(f, "(max-height: {})", height),
+            MediaFeature::PrefersColorScheme(ColorScheme::Light) => write!(f, "(prefers-color-scheme: light)"),
+            MediaFeature::PrefersColorScheme(ColorScheme::Dark) => write!(f, "(prefers-color-scheme: dark)"),
+            MediaFeature::PrefersReducedMotion(ReducedMotion::NoPreference) => write!(f, "(prefers-reduced-motion: no-preference)"),
+            MediaFeature::PrefersReducedMotion(ReducedMotion::Reduce) => write!(f, "(prefers-reduced-motion: reduce)"),
+            MediaFeature::Orientation(Orientation::Portrait) => write!(f, "(orientation: portrait)"),
+            MediaFeature::Orientation(Orientation::Landscape) => write!(f, "(orientation: landscape)"),
+            MediaFeature::Raw(condition) => write!(f, "{}", condition.trim()),
+        }
+    }
+}
+
+/// A media query with an optional media type and features that all have to match,
+/// written as `screen and (min-width: 600px) and (prefers-color-scheme: dark)`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MediaQuery {
+    pub media_type: Option<MediaType>,
+    pub features: Vec<MediaFeature>,
+}
+
+impl MediaQuery {
+    // a query that matches everything until features are added
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // a query that is written as-is (i.e. `screen and (hover: hover)`)
+    pub fn raw(query: &str) -> Self {
+        Self::new().and(MediaFeature::Raw(query.to_owned()))
+    }
+
+    pub fn media_type(mut self, media_type: MediaType) -> Self {
+        self.media_type = Some(media_type);
+        self
+    }
+
+    pub fn screen(self) -> Self {
+        self.media_type(MediaType::Screen)
+    }
+
+    pub fn print(self) -> Self {
+        self.media_type(MediaType::Print)
+    }
+
+    pub fn and(mut self, feature: MediaFeature) -> Self {
+        self.features.push(feature);
+        self
+    }
+
+    pub fn min_width(self, width: Length) -> Self {
+        self.and(MediaFeature::MinWidth(width))
+    }
+
+    pub fn max_width(self, width: Length) -> Self {
+        self.and(MediaFeature::MaxWidth(width))
+    }
+
+    pub fn min_height(self, height: Length) -> Self {
+        self.and(MediaFeature::MinHeight(height))
+    }
+
+    pub fn max_height(self, height: Length) -> Self {
+        self.and(MediaFeature::MaxHeight(height))
+    }
+
+    pub fn prefers_color_scheme(self, scheme: ColorScheme) -> Self {
+        self.and(MediaFeature::PrefersColorScheme(scheme))
+    }
+
+    pub fn prefers_reduced_motion(self) -> Self {
+        self.and(MediaFeature::PrefersReducedMotion(ReducedMotion::Reduce))
+    }
+
+    pub fn orientation(self, orientation: Orientation) -> Self {
+        self.and(MediaFeature::Orientation(orientation))
+    }
+
+    // the rules wrapped in an @media block for this query
+    pub fn wrap(&self, rules: &str) -> String {
+        format!("@media {} {{ {} }}", self, rules)
+    }
+}
+
+impl fmt::Display for MediaQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        match self.media_type {
+            Some(MediaType::All) => parts.push("all".to_owned()),
+            Some(MediaType::Screen) => parts.push("screen".to_owned()),
+            Some(MediaType::Print) => parts.push("print".to_owned()),
+            None => {},
+        }
+        parts.extend(self.features.iter().map(MediaFeature::to_string));
+
+        if parts.is_empty() {
+            write!(f, "all")
+        } else {
+            write!(f, "{}", parts.join(" and "))
+        }
+    }
+}
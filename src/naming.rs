@@ -0,0 +1,141 @@
+// struct fields are written as kebab-cased css names (border_radius -> border-radius, r#type -> type),
+// Style fields can be given any other name with #[css(rename = "...")],
+// classes are named after the type of the style with a ClassNaming strategy
+
+use crate::Style;
+use bevy_reflect::Struct;
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+// the css name of a field that wasn't renamed
+pub(crate) fn field_css_name(field_name: &str) -> String {
+    field_name.trim_start_matches("r#").replace('_', "-")
+}
+
+// #[derive(Style)] uses this to find the names that the type of a field gives to its own fields if it's a Style itself,
+// (&&Renames::<T>::new()).nested_property(..) picks RenamesStyle if T implements Style and falls back to RenamesFallback
+#[doc(hidden)]
+pub struct Renames<T>(PhantomData<T>);
+
+impl<T> Renames<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Renames(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait RenamesStyle {
+    fn nested_property(&self, nested_type: TypeId, field_name: &str) -> Option<String>;
+}
+
+impl<T: Style> RenamesStyle for &Renames<T> {
+    fn nested_property(&self, nested_type: TypeId, field_name: &str) -> Option<String> {
+        (nested_type == TypeId::of::<T>()).then(|| T::property_name(field_name))
+    }
+}
+
+#[doc(hidden)]
+pub trait RenamesFallback {
+    fn nested_property(&self, nested_type: TypeId, field_name: &str) -> Option<String>;
+}
+
+impl<T> RenamesFallback for Renames<T> {
+    fn nested_property(&self, _nested_type: TypeId, _field_name: &str) -> Option<String> {
+        None
+    }
+}
+
+// the name of the field that is written as the given css name
+pub(crate) fn find_field<'a>(struct_reflect: &'a dyn Struct, css_name: &str, property_name: impl Fn(&str) -> String) -> Option<&'a str> {
+    (0..struct_reflect.field_len())
+        .map(|i| struct_reflect.name_at(i).unwrap())
+        .find(|field_name| property_name(field_name) == css_name)
+}
+
+/// How `as_class()` and `add_as_pseudo_class()` name the class of a style. Every strategy produces a valid
+/// css identifier, the generics of a type are written after its name (`Wrapper<Foo>` is `Wrapper-Foo`).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ClassNaming {
+    // the name of the type with the path of its module (my_app-components-Button)
+    #[default]
+    ModulePath,
+    // the name of the type followed by a hash of the path of its module (Button-5c2a9e1f), unique but still short
+    Hash,
+    // the name of the type (Button), styles of the same name in different modules share a class
+    TypeName,
+    // the name of the type after the given prefix (app-Button), unique as long as the prefix is
+    Prefix(String),
+}
+
+impl ClassNaming {
+    // the class name for the full path of a type, as it's given by Style::type_path
+    pub fn class_name(&self, type_path: &str) -> String {
+        match self {
+            ClassNaming::ModulePath => css_identifier(type_path),
+            ClassNaming::Hash => format!("{}-{:08x}", css_identifier(&short_type_name(type_path)), hash(type_path)),
+            ClassNaming::TypeName => css_identifier(&short_type_name(type_path)),
+            ClassNaming::Prefix(prefix) => css_identifier(&format!("{}-{}", prefix, short_type_name(type_path))),
+        }
+    }
+}
+
+// the type name without any module paths (my_app::Wrapper<my_app::Foo> -> Wrapper<Foo>)
+fn short_type_name(type_name: &str) -> String {
+    let mut short = String::new();
+    let mut segment_start = 0;
+    for (i, c) in type_name.char_indices() {
+        if !(c.is_alphanumeric() || c == '_' || c == ':') {
+            short.push_str(last_path_segment(&type_name[segment_start..i]));
+            short.push(c);
+            segment_start = i + c.len_utf8();
+        }
+    }
+    short.push_str(last_path_segment(&type_name[segment_start..]));
+    short
+}
+
+fn last_path_segment(path: &str) -> &str {
+    path.rfind("::").map(|pos| &path[pos + 2..]).unwrap_or(path)
+}
+
+// letters, digits, _ and - are kept, everything else becomes a single -,
+// a leading digit would make it a number so it gets an _ in front
+pub(crate) fn css_identifier(name: &str) -> String {
+    let mut identifier = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+            identifier.push(c);
+        } else if !identifier.is_empty() && !identifier.ends_with('-') {
+            identifier.push('-');
+        }
+    }
+    let identifier = identifier.trim_end_matches('-');
+    let starts_with_number = identifier.trim_start_matches('-').starts_with(|c: char| c.is_ascii_digit());
+    if identifier.is_empty() || starts_with_number {
+        format!("_{}", identifier)
+    } else {
+        identifier.to_owned()
+    }
+}
+
+// splits the pseudo-class off the name of a type, everything after the first _ of the name
+// with every _ turned into a : (my_app::Button_hover -> my_app::Button and :hover)
+pub(crate) fn split_pseudo_class(type_name: &str) -> (String, String) {
+    let generics_start = type_name.find('<').unwrap_or(type_name.len());
+    let name_start = type_name[..generics_start].rfind("::").map(|pos| pos + 2).unwrap_or(0);
+    match type_name[name_start..generics_start].find('_') {
+        Some(pos) => {
+            let pseudo_start = name_start + pos;
+            let base = format!("{}{}", &type_name[..pseudo_start], &type_name[generics_start..]);
+            (base, type_name[pseudo_start..generics_start].replace('_', ":"))
+        },
+        None => (type_name.to_owned(), String::new()),
+    }
+}
+
+// 32 bit FNV-1a, which gives the same hash on every platform,
+// so class names rendered on the server match the ones in the browser
+pub(crate) fn hash(text: &str) -> u32 {
+    text.bytes().fold(0x811c9dc5, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x01000193))
+}
@@ -0,0 +1,64 @@
+// conversion between css text and the primitive number and bool types a field can have
+
+use crate::error::{ Error, offset_in };
+use bevy_reflect::Reflect;
+
+// renders a bool or number field, numbers get the unit appended (i.e. 10 and "px" -> 10px)
+pub(crate) fn to_css(reflect: &dyn Reflect, unit: Option<&str>) -> Option<String> {
+    if let Some(boolean) = reflect.downcast_ref::<bool>() {
+        return Some(boolean.to_string());
+    }
+
+    macro_rules! number_to_css {
+        ($($number:ty),*) => {
+            $(
+                if let Some(number) = reflect.downcast_ref::<$number>() {
+                    return Some(format!("{}{}", number, unit.unwrap_or("")));
+                }
+            )*
+        };
+    }
+    number_to_css!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+    None
+}
+
+// sets a bool or number field from its css text, returns None if the field is neither
+pub(crate) fn set_from_css(reflect: &mut dyn Reflect, value: &str, unit: Option<&str>) -> Option<Result<(), Error>> {
+    let trimmed = value.trim();
+    let offset = offset_in(value, trimmed);
+
+    if let Some(boolean) = reflect.downcast_mut::<bool>() {
+        return Some(match trimmed {
+            "true" => { *boolean = true; Ok(()) },
+            "false" => { *boolean = false; Ok(()) },
+            _ => Err(Error::parse(offset, format!("expected `true` or `false`, found `{}`", trimmed))),
+        });
+    }
+
+    // the unit is optional in the css text, so unitless zeros (i.e. `margin: 0`) can be read
+    let number = match unit {
+        Some(unit) => trimmed.strip_suffix(unit).unwrap_or(trimmed),
+        None => trimmed,
+    };
+
+    macro_rules! number_from_css {
+        ($($number:ty),*) => {
+            $(
+                if let Some(field) = reflect.downcast_mut::<$number>() {
+                    return Some(match number.parse::<$number>() {
+                        Ok(parsed) => { *field = parsed; Ok(()) },
+                        Err(_) => Err(Error::parse(offset, format!("expected {} number{}, found `{}`",
+                            stringify!($number),
+                            unit.map(|unit| format!(" in {}", unit)).unwrap_or_default(),
+                            trimmed,
+                        ))),
+                    });
+                }
+            )*
+        };
+    }
+    number_from_css!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+    None
+}
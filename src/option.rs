@@ -0,0 +1,33 @@
+// Option fields are left out of the css while they're None
+
+use crate::blank;
+use bevy_reflect::{ Enum, Reflect, ReflectRef, TypeInfo, VariantInfo };
+
+// an Option is told apart from other enums by its variants, as type names aren't stable
+pub(crate) fn is_option(enum_reflect: &dyn Enum) -> bool {
+    match enum_reflect.get_type_info() {
+        TypeInfo::Enum(info) => info.variant_len() == 2
+            && matches!(info.variant("None"), Some(VariantInfo::Unit(_)))
+            && matches!(info.variant("Some"), Some(VariantInfo::Tuple(some)) if some.field_len() == 1),
+        _ => false,
+    }
+}
+
+// true for an Option that is None, which shouldn't be rendered at all
+pub(crate) fn is_none(reflect: &dyn Reflect) -> bool {
+    match reflect.reflect_ref() {
+        ReflectRef::Enum(enum_reflect) => is_option(enum_reflect) && enum_reflect.variant_name() == "None",
+        _ => false,
+    }
+}
+
+// a fresh value of the type inside of the Option, for Strings, bools and numbers
+pub(crate) fn blank_inner(option_reflect: &dyn Enum) -> Option<Box<dyn Reflect>> {
+    match option_reflect.get_type_info() {
+        TypeInfo::Enum(info) => match info.variant("Some")? {
+            VariantInfo::Tuple(some) => blank::blank_value(some.field_at(0)?.type_name()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,84 @@
+//! Where `as_class()` and the `append_to_head()` methods put their css: the `<head>` of a `web_sys::Document`
+//! in the browser, or a `MemoryRegistry` when rendering on the server.
+
+use crate::dom;
+use crate::error::Error;
+use web_sys::Document;
+
+/// Receives the css of classes, keyframes, font faces and imports under a name each.
+/// Css that is inserted under a name that was used before replaces the css from before.
+pub trait StyleRegistry {
+    fn insert(&mut self, name: &str, css: &str) -> Result<(), Error>;
+}
+
+// every name gets a <style id="rusty-css-{name}"> element in the <head>,
+// an element that already holds the same css (i.e. rendered on the server) is left alone
+impl StyleRegistry for Document {
+    fn insert(&mut self, name: &str, css: &str) -> Result<(), Error> {
+        dom::append_style_element(self, name, css)
+    }
+}
+
+impl StyleRegistry for &Document {
+    fn insert(&mut self, name: &str, css: &str) -> Result<(), Error> {
+        dom::append_style_element(self, name, css)
+    }
+}
+
+/// Collects the css in memory, so it works without a browser. `to_html()` writes the same `<style>` elements
+/// the `Document` would get, which the browser then keeps instead of inserting them again.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MemoryRegistry {
+    // the name and css of every entry in the order they were inserted
+    entries: Vec<(String, String)>,
+}
+
+impl MemoryRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // puts the css under the name, replacing the css that was there under the same name before
+    pub fn insert(&mut self, name: &str, css: &str) {
+        match self.entries.iter_mut().find(|(entry_name, _)| entry_name == name) {
+            Some(entry) => entry.1 = css.to_owned(),
+            None => self.entries.push((name.to_owned(), css.to_owned())),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.entries.iter().find(|(entry_name, _)| entry_name == name).map(|(_, css)| css.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    // the css of all entries, one per line
+    pub fn to_css(&self) -> String {
+        self.entries.iter().map(|(_, css)| css.as_str()).collect::<Vec<&str>>().join("\n")
+    }
+
+    // a <style> element for every entry, to be put into the <head> of the html
+    pub fn to_html(&self) -> String {
+        self.entries.iter()
+            .map(|(name, css)| format!("<style id=\"{}\">{}</style>", dom::style_id(name), dom::escape_style_text(css)))
+            .collect()
+    }
+}
+
+// only a &mut MemoryRegistry is a registry, a MemoryRegistry that is passed by value would be dropped with the css
+impl StyleRegistry for &mut MemoryRegistry {
+    fn insert(&mut self, name: &str, css: &str) -> Result<(), Error> {
+        MemoryRegistry::insert(self, name, css);
+        Ok(())
+    }
+}
@@ -0,0 +1,356 @@
+//! Selectors that are appended to the class of a style, like `:hover`, `::before`, `[disabled]` or ` > li`.
+//! They're kept apart from the fields of a style, so they don't take up property names.
+
+use crate::error::Error;
+use crate::split;
+use crate::tokenizer::{ tokenize, Token, TokenKind };
+use std::fmt;
+use std::str::FromStr;
+
+// how the element matched by the next parts relates to the one matched by the parts before
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combinator {
+    // `a b`
+    Descendant,
+    // `a > b`
+    Child,
+    // `a + b`
+    NextSibling,
+    // `a ~ b`
+    SubsequentSibling,
+}
+
+// how the value of an attribute is compared in an attribute selector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeMatcher {
+    // `[a=v]`
+    Equals,
+    // `[a~=v]`, v is one of the whitespace separated words
+    Includes,
+    // `[a|=v]`, v or v followed by a hyphen
+    DashMatch,
+    // `[a^=v]`
+    Prefix,
+    // `[a$=v]`
+    Suffix,
+    // `[a*=v]`
+    Substring,
+}
+
+impl AttributeMatcher {
+    pub fn operator(&self) -> &'static str {
+        match self {
+            AttributeMatcher::Equals => "=",
+            AttributeMatcher::Includes => "~=",
+            AttributeMatcher::DashMatch => "|=",
+            AttributeMatcher::Prefix => "^=",
+            AttributeMatcher::Suffix => "$=",
+            AttributeMatcher::Substring => "*=",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectorPart {
+    // `li`
+    Type(String),
+    // `*`
+    Universal,
+    // `.active`, without the dot
+    Class(String),
+    // `#main`, without the hash
+    Id(String),
+    // `[disabled]` or `[type="text"]`, the value is unquoted
+    Attribute { name: String, matcher: Option<(AttributeMatcher, String)> },
+    // `:hover` or `:nth-child(2n + 1)`, without the colon
+    PseudoClass(String),
+    // `::before`, without the colons
+    PseudoElement(String),
+    Combinator(Combinator),
+}
+
+impl fmt::Display for SelectorPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelectorPart::Type(name) => write!(f, "{}", name),
+            SelectorPart::Universal => write!(f, "*"),
+            SelectorPart::Class(name) => write!(f, ".{}", name),
+            SelectorPart::Id(name) => write!(f, "#{}", name),
+            SelectorPart::Attribute { name, matcher: None } => write!(f, "[{}]", name),
+            SelectorPart::Attribute { name, matcher: Some((matcher, value)) } => {
+                write!(f, "[{}{}\"{}\"]", name, matcher.operator(), value.replace('\\', "\\\\").replace('"', "\\\""))
+            },
+            SelectorPart::PseudoClass(name) => write!(f, ":{}", name),
+            SelectorPart::PseudoElement(name) => write!(f, "::{}", name),
+            SelectorPart::Combinator(Combinator::Descendant) => write!(f, " "),
+            SelectorPart::Combinator(Combinator::Child) => write!(f, " > "),
+            SelectorPart::Combinator(Combinator::NextSibling) => write!(f, " + "),
+            SelectorPart::Combinator(Combinator::SubsequentSibling) => write!(f, " ~ "),
+        }
+    }
+}
+
+/// The parts of a selector that follow the class of a style (`.Button:hover > .icon`).
+/// Build it with the methods below or parse it from css, a leading space makes
+/// the rest a descendant of the class (` li` is `.List li`, `li` would be `.Listli`).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Selector {
+    pub parts: Vec<SelectorPart>,
+}
+
+impl Selector {
+    // a selector that selects the class itself
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    pub fn then(mut self, part: SelectorPart) -> Self {
+        self.parts.push(part);
+        self
+    }
+
+    pub fn element(self, name: &str) -> Self {
+        self.then(SelectorPart::Type(name.to_owned()))
+    }
+
+    pub fn universal(self) -> Self {
+        self.then(SelectorPart::Universal)
+    }
+
+    pub fn class(self, name: &str) -> Self {
+        self.then(SelectorPart::Class(name.to_owned()))
+    }
+
+    pub fn id(self, name: &str) -> Self {
+        self.then(SelectorPart::Id(name.to_owned()))
+    }
+
+    // `[name]`
+    pub fn attribute(self, name: &str) -> Self {
+        self.then(SelectorPart::Attribute { name: name.to_owned(), matcher: None })
+    }
+
+    // `[name="value"]` and the other comparisons
+    pub fn attribute_matches(self, name: &str, matcher: AttributeMatcher, value: &str) -> Self {
+        self.then(SelectorPart::Attribute { name: name.to_owned(), matcher: Some((matcher, value.to_owned())) })
+    }
+
+    // the name can have arguments (nth-child(2n))
+    pub fn pseudo_class(self, name: &str) -> Self {
+        self.then(SelectorPart::PseudoClass(name.to_owned()))
+    }
+
+    pub fn pseudo_element(self, name: &str) -> Self {
+        self.then(SelectorPart::PseudoElement(name.to_owned()))
+    }
+
+    pub fn hover(self) -> Self {
+        self.pseudo_class("hover")
+    }
+
+    pub fn focus(self) -> Self {
+        self.pseudo_class("focus")
+    }
+
+    pub fn active(self) -> Self {
+        self.pseudo_class("active")
+    }
+
+    pub fn before(self) -> Self {
+        self.pseudo_element("before")
+    }
+
+    pub fn after(self) -> Self {
+        self.pseudo_element("after")
+    }
+
+    pub fn descendant(self) -> Self {
+        self.then(SelectorPart::Combinator(Combinator::Descendant))
+    }
+
+    pub fn child(self) -> Self {
+        self.then(SelectorPart::Combinator(Combinator::Child))
+    }
+
+    pub fn next_sibling(self) -> Self {
+        self.then(SelectorPart::Combinator(Combinator::NextSibling))
+    }
+
+    pub fn subsequent_sibling(self) -> Self {
+        self.then(SelectorPart::Combinator(Combinator::SubsequentSibling))
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in &self.parts {
+            write!(f, "{}", part)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Selector {
+    type Err = Error;
+
+    fn from_str(css: &str) -> Result<Self, Error> {
+        let tokens = tokenize(css);
+        let mut selector = Selector::new();
+        let mut after_whitespace = false;
+        let mut i = 0;
+        while i < tokens.len() {
+            let token = tokens[i];
+            i += 1;
+
+            let combinator = match token.kind {
+                _ if token.is_trivia() => {
+                    after_whitespace = true;
+                    continue;
+                },
+                TokenKind::Delim('>') => Some(Combinator::Child),
+                TokenKind::Delim('+') => Some(Combinator::NextSibling),
+                TokenKind::Delim('~') => Some(Combinator::SubsequentSibling),
+                _ => None,
+            };
+            // whitespace only combines the parts around it if there's no other combinator
+            if after_whitespace && combinator.is_none() && !matches!(selector.parts.last(), Some(SelectorPart::Combinator(_))) {
+                selector.parts.push(SelectorPart::Combinator(Combinator::Descendant));
+            }
+            after_whitespace = false;
+            if let Some(combinator) = combinator {
+                selector.parts.push(SelectorPart::Combinator(combinator));
+                continue;
+            }
+
+            let part = match token.kind {
+                TokenKind::Ident => SelectorPart::Type(token.text(css).to_owned()),
+                TokenKind::Delim('*') => SelectorPart::Universal,
+                TokenKind::Hash => SelectorPart::Id(token.text(css)[1..].to_owned()),
+                TokenKind::Delim('.') => match tokens.get(i) {
+                    Some(name) if name.kind == TokenKind::Ident => {
+                        i += 1;
+                        SelectorPart::Class(name.text(css).to_owned())
+                    },
+                    _ => return Err(Error::parse(token.end, "expected a class name after `.`")),
+                },
+                TokenKind::Colon => {
+                    let is_element = tokens.get(i).is_some_and(|colon| colon.kind == TokenKind::Colon);
+                    if is_element { i += 1; }
+                    let name = pseudo_name(css, &tokens, &mut i)
+                        .ok_or_else(|| Error::parse(tokens[i - 1].end, "expected the name of a pseudo-class or pseudo-element"))?;
+                    if is_element { SelectorPart::PseudoElement(name) } else { SelectorPart::PseudoClass(name) }
+                },
+                TokenKind::OpenSquare => attribute(css, &tokens, &mut i, token)?,
+                _ => return Err(Error::parse(token.start, format!("unexpected `{}` in a selector", token.text(css)))),
+            };
+            selector.parts.push(part);
+        }
+
+        Ok(selector)
+    }
+}
+
+// the name of a pseudo-class or pseudo-element after its colons, with its arguments if it has any (nth-child(2n + 1))
+fn pseudo_name(css: &str, tokens: &[Token], i: &mut usize) -> Option<String> {
+    let name = tokens.get(*i)?;
+    match name.kind {
+        TokenKind::Ident => {
+            *i += 1;
+            Some(name.text(css).to_owned())
+        },
+        TokenKind::Function => {
+            let mut depth = 0;
+            let close = tokens[*i..].iter().position(|token| {
+                if token.opens_block() { depth += 1 }
+                if token.closes_block() { depth -= 1 }
+                depth == 0
+            })?;
+            *i += close + 1;
+            Some(css[name.start..tokens[*i - 1].end].to_owned())
+        },
+        _ => None,
+    }
+}
+
+// an attribute selector, the opening bracket is already consumed
+fn attribute(css: &str, tokens: &[Token], i: &mut usize, open: Token) -> Result<SelectorPart, Error> {
+    let close = tokens[*i..].iter().position(|token| token.kind == TokenKind::CloseSquare)
+        .ok_or_else(|| Error::parse(open.start, "unclosed bracket of an attribute selector"))?;
+    let inner = tokens[*i..*i + close].iter().filter(|token| !token.is_trivia()).collect::<Vec<&Token>>();
+    *i += close + 1;
+
+    let expected = || Error::parse(open.start, format!("expected an attribute selector like `[name]` or `[name=\"value\"]`, found `{}`", &css[open.start..tokens[*i - 1].end]));
+    let name = match inner.first() {
+        Some(name) if name.kind == TokenKind::Ident => name.text(css).to_owned(),
+        _ => return Err(expected()),
+    };
+
+    let (matcher, rest) = match inner[1..] {
+        [] => return Ok(SelectorPart::Attribute { name, matcher: None }),
+        [equals, ref rest @ ..] if equals.kind == TokenKind::Delim('=') => (AttributeMatcher::Equals, rest),
+        [operator, equals, ref rest @ ..] if equals.kind == TokenKind::Delim('=') && equals.start == operator.end => {
+            let matcher = match operator.kind {
+                TokenKind::Delim('~') => AttributeMatcher::Includes,
+                TokenKind::Delim('|') => AttributeMatcher::DashMatch,
+                TokenKind::Delim('^') => AttributeMatcher::Prefix,
+                TokenKind::Delim('$') => AttributeMatcher::Suffix,
+                TokenKind::Delim('*') => AttributeMatcher::Substring,
+                _ => return Err(expected()),
+            };
+            (matcher, rest)
+        },
+        _ => return Err(expected()),
+    };
+
+    let value = match rest {
+        [value] if value.kind == TokenKind::Ident => value.text(css).to_owned(),
+        [value] if value.kind == TokenKind::String => unescape(&value.text(css)[1..value.text(css).len() - 1]),
+        _ => return Err(expected()),
+    };
+    Ok(SelectorPart::Attribute { name, matcher: Some((matcher, value)) })
+}
+
+// a quoted string without its escapes, `\"` is `"`
+fn unescape(quoted: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            c => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// The selector of a nested rule, with every `&` replaced by the selector of the rule around it
+/// (`&:hover` in `.Button` is `.Button:hover`). A selector without `&` selects the elements inside of it.
+/// Selector lists are nested one selector at a time (`a, b` in `.Button` is `.Button a, .Button b`).
+pub fn nest(nested: &str, parent: &str) -> String {
+    let parents = split::split_commas(parent);
+    split::split_commas(nested).into_iter()
+        .flat_map(|nested| parents.iter().map(move |parent| nest_one(nested, parent)))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn nest_one(nested: &str, parent: &str) -> String {
+    let ampersands = tokenize(nested).into_iter().filter(|token| token.kind == TokenKind::Delim('&')).collect::<Vec<Token>>();
+    if ampersands.is_empty() {
+        return format!("{} {}", parent, nested);
+    }
+
+    let mut selector = String::new();
+    let mut start = 0;
+    for ampersand in ampersands {
+        selector.push_str(&nested[start..ampersand.start]);
+        selector.push_str(parent);
+        start = ampersand.end;
+    }
+    selector.push_str(&nested[start..]);
+    selector
+}
@@ -0,0 +1,194 @@
+// splits css text into the parts the fields are read from, using the tokenizer so separators inside of
+// strings, comments, escapes and any kind of brackets are left alone,
+// the parts are slices of the given css so errors can point back into it
+
+use crate::error::Error;
+use crate::tokenizer::{ tokenize, Token, TokenKind };
+
+// the css without whitespace and comments around it
+pub(crate) fn trim(css: &str) -> &str {
+    let tokens = tokenize(css);
+    match (tokens.iter().find(|token| !token.is_trivia()), tokens.iter().rev().find(|token| !token.is_trivia())) {
+        (Some(first), Some(last)) => &css[first.start..last.end],
+        _ => &css[css.len()..],
+    }
+}
+
+// the tokens of the css that aren't inside of any block
+fn top_level(tokens: &[Token]) -> Vec<&Token> {
+    let mut depth = 0usize;
+    let mut top_level = Vec::new();
+    for token in tokens {
+        if token.closes_block() {
+            depth = depth.saturating_sub(1);
+        }
+        if depth == 0 {
+            top_level.push(token);
+        }
+        if token.opens_block() {
+            depth += 1;
+        }
+    }
+    top_level
+}
+
+// the trimmed parts between the separators that aren't inside of a block
+fn split_at(css: &str, is_separator: impl Fn(&Token) -> bool) -> Vec<&str> {
+    let tokens = tokenize(css);
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for token in top_level(&tokens) {
+        if is_separator(token) {
+            parts.push(trim(&css[start..token.start]));
+            start = token.end;
+        }
+    }
+    parts.push(trim(&css[start..]));
+    parts
+}
+
+// the rules of a stylesheet (.a { } @media print { .b { } } @import url(c.css);),
+// a rule ends with its block or, for rules without one, with a semicolon
+pub(crate) fn split_rules(css: &str) -> Vec<&str> {
+    let tokens = tokenize(css);
+    let mut rules = Vec::new();
+    let mut start = 0;
+    let mut depth = 0usize;
+    for token in &tokens {
+        if token.opens_block() { depth += 1; }
+        if token.closes_block() { depth = depth.saturating_sub(1); }
+        let ends_rule = depth == 0 && matches!(token.kind, TokenKind::CloseCurly | TokenKind::Semicolon);
+        if ends_rule {
+            rules.push(trim(&css[start..token.end]));
+            start = token.end;
+        }
+    }
+    rules.push(trim(&css[start..]));
+    rules.retain(|rule| !rule.is_empty());
+    rules
+}
+
+// the declarations of an inline style (width: 10px; height: 20px)
+pub(crate) fn split_declarations(css: &str) -> Vec<&str> {
+    split_at(css, |token| token.kind == TokenKind::Semicolon)
+}
+
+// the property and value of a declaration (width: 10px)
+pub(crate) fn split_property(css: &str) -> Option<(&str, &str)> {
+    let tokens = tokenize(css);
+    let colon = tokens.iter().find(|token| token.kind == TokenKind::Colon)?;
+    Some((trim(&css[..colon.start]), trim(&css[colon.end..])))
+}
+
+// the value of a declaration without the `!important` flag at its end, and whether it had the flag (10px !important)
+pub(crate) fn split_important(css: &str) -> (&str, bool) {
+    let tokens = tokenize(css);
+    let mut significant = top_level(&tokens).into_iter().rev().filter(|token| !token.is_trivia());
+    match (significant.next(), significant.next()) {
+        (Some(important), Some(bang)) if bang.kind == TokenKind::Delim('!')
+            && important.kind == TokenKind::Ident
+            && important.text(css).eq_ignore_ascii_case("important") => (trim(&css[..bang.start]), true),
+        _ => (trim(css), false),
+    }
+}
+
+// the values of a list (a, b, c), an empty css has no values
+pub(crate) fn split_commas(css: &str) -> Vec<&str> {
+    if trim(css).is_empty() {
+        return Vec::new();
+    }
+    split_at(css, |token| token.kind == TokenKind::Comma)
+}
+
+// the trimmed parts in front of and behind the first slash that isn't inside of a block (0 0 0 / 50%)
+pub(crate) fn split_slash(css: &str) -> Option<(&str, &str)> {
+    let tokens = tokenize(css);
+    let slash = top_level(&tokens).into_iter().find(|token| token.kind == TokenKind::Delim('/'))?;
+    Some((trim(&css[..slash.start]), trim(&css[slash.end..])))
+}
+
+// the values of a tuple (a b c), all whitespace and comments between them separate them,
+// once there are limit - 1 values the last one gets whatever is left
+pub(crate) fn split_whitespace(css: &str, limit: usize) -> Vec<&str> {
+    let css = trim(css);
+    if css.is_empty() {
+        return Vec::new();
+    }
+
+    let tokens = tokenize(css);
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut separator_start = None;
+    for token in top_level(&tokens) {
+        if parts.len() + 1 >= limit {
+            break;
+        }
+        match (token.is_trivia(), separator_start) {
+            (true, None) => separator_start = Some(token.start),
+            (false, Some(end)) => {
+                parts.push(&css[start..end]);
+                start = token.start;
+                separator_start = None;
+            },
+            _ => {},
+        }
+    }
+    parts.push(&css[start..]);
+    parts
+}
+
+// a css function call like `name(arguments)`
+pub(crate) struct Function<'a> {
+    // the whole call
+    pub text: &'a str,
+    pub name: &'a str,
+    pub args: &'a str,
+}
+
+// the function calls the css consists of (abc(i) def(j k)),
+// anything else than functions separated by whitespace is an error
+pub(crate) fn functions(css: &str) -> Result<Vec<Function<'_>>, Error> {
+    let tokens = tokenize(css);
+    let mut functions = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+        i += 1;
+        match token.kind {
+            _ if token.is_trivia() => {},
+            TokenKind::Url => {
+                let end = if css[..token.end].ends_with(')') { token.end - 1 } else { token.end };
+                let open = token.start + css[token.start..].find('(').unwrap();
+                functions.push(Function { text: token.text(css), name: &css[token.start..open], args: &css[open + 1..end] });
+            },
+            TokenKind::Function => {
+                let name = &css[token.start..token.end - 1];
+                let mut depth = 1;
+                let close = tokens[i..].iter().position(|token| {
+                    if token.opens_block() { depth += 1 }
+                    if token.closes_block() { depth -= 1 }
+                    depth == 0
+                });
+                let close = close.ok_or_else(|| Error::parse(token.end - 1, format!("unclosed parenthesis of `{}(`", name)))?;
+                i += close + 1;
+                let close = tokens[i - 1];
+                functions.push(Function { text: &css[token.start..close.end], name, args: &css[token.end..close.start] });
+            },
+            _ => {
+                let message = format!("expected a css function like `name(arguments)`, found `{}`", css[token.start..].trim_end());
+                return Err(Error::parse(token.start, message));
+            },
+        }
+    }
+    Ok(functions)
+}
+
+// the name and arguments of a css that consists of a single function call, None for anything else
+pub(crate) fn function(css: &str) -> Option<Function<'_>> {
+    let trimmed = trim(css);
+    let mut functions = functions(trimmed).ok()?;
+    match functions.pop() {
+        Some(function) if functions.is_empty() && trimmed.ends_with(')') => Some(function),
+        _ => None,
+    }
+}
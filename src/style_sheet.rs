@@ -0,0 +1,223 @@
+//! A stylesheet that collects the rules of many styles and writes them into a single `<style>` element.
+
+use crate::error::Error;
+use crate::font_face::FontFace;
+use crate::import::Import;
+use crate::keyframes::Keyframes;
+use crate::split;
+use crate::Style;
+use wasm_bindgen::JsCast;
+use web_sys::{ CssStyleSheet, Document, HtmlStyleElement };
+
+/// Collects classes, keyframes, font faces, imports and any other rules under a key each, so adding the same
+/// style again only replaces its rules. `flush()` writes all of them into one `<style>` element and afterwards
+/// only inserts and deletes the rules that changed since the last flush.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleSheet {
+    // the id of the <style> element
+    id: String,
+    // the key and css of every entry, imports first and the rest in the order they were added
+    entries: Vec<(String, String)>,
+    // the key, css and number of rules of the entries in the <style> element, None before the first flush
+    flushed: Option<Vec<(String, String, usize)>>,
+}
+
+impl Default for StyleSheet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StyleSheet {
+    // a stylesheet that is written into the element with the id rusty-css
+    pub fn new() -> Self {
+        Self::with_id("rusty-css")
+    }
+
+    pub fn with_id(id: &str) -> Self {
+        Self { id: id.to_owned(), entries: Vec::new(), flushed: None }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    // adds the style as a class named after the struct, like Style::as_class does, and returns the class name
+    pub fn add_class<S: Style>(&mut self, style: &S) -> Result<String, Error> {
+        let class_name = style.get_struct_name()?;
+        self.add_class_named(style, &class_name)?;
+        Ok(class_name)
+    }
+
+    // adds the style as a class named after its css, like Style::as_unique_class does, and returns the class name
+    pub fn add_unique_class<S: Style>(&mut self, style: &S) -> Result<String, Error> {
+        let class_name = style.unique_class_name()?;
+        self.add_class_named(style, &class_name)?;
+        Ok(class_name)
+    }
+
+    pub fn add_class_named<S: Style>(&mut self, style: &S, class_name: &str) -> Result<(), Error> {
+        let css = style.as_class_string(class_name)?;
+        self.add_rule(&format!(".{}", class_name), &css);
+        Ok(())
+    }
+
+    // adds the keyframes and returns the name of the animation
+    pub fn add_keyframes(&mut self, keyframes: &Keyframes) -> String {
+        self.add_rule(&format!("@keyframes {}", keyframes.name()), &keyframes.to_css());
+        keyframes.name().to_owned()
+    }
+
+    // a font face of the same family, weight and style replaces the one that was added before
+    pub fn add_font_face(&mut self, font_face: &FontFace) {
+        self.add_rule(&font_face.key(), &font_face.to_css());
+    }
+
+    // imports go before all other rules, since the browser ignores them anywhere else
+    pub fn add_import(&mut self, import: &Import) {
+        let key = format!("@import {}", import.url);
+        let css = import.to_css();
+        if let Some(entry) = self.entries.iter_mut().find(|(entry_key, _)| *entry_key == key) {
+            entry.1 = css;
+        } else {
+            let imports = self.entries.iter().take_while(|(entry_key, _)| entry_key.starts_with("@import ")).count();
+            self.entries.insert(imports, (key, css));
+        }
+    }
+
+    // adds any css under the given key, the css of an entry that already has the key is replaced in place
+    pub fn add_rule(&mut self, key: &str, css: &str) {
+        match self.entries.iter_mut().find(|(entry_key, _)| entry_key == key) {
+            Some(entry) => entry.1 = css.to_owned(),
+            None => self.entries.push((key.to_owned(), css.to_owned())),
+        }
+    }
+
+    // the keys are .ClassName for classes, @keyframes name for keyframes, @import url for imports and FontFace::key() for font faces
+    pub fn remove(&mut self, key: &str) -> bool {
+        let len = self.entries.len();
+        self.entries.retain(|(entry_key, _)| entry_key != key);
+        self.entries.len() != len
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.iter().any(|(entry_key, _)| entry_key == key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // the css of all entries, one per line
+    pub fn to_css(&self) -> String {
+        self.entries.iter().map(|(_, css)| css.as_str()).collect::<Vec<&str>>().join("\n")
+    }
+
+    pub fn flush(&mut self, document: &Document) {
+        self.try_flush(document).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    // writes the stylesheet into its <style> element in the <head>, creating the element if it isn't there yet
+    pub fn try_flush(&mut self, document: &Document) -> Result<(), Error> {
+        let element = match document.get_element_by_id(&self.id) {
+            Some(element) => element,
+            None => {
+                let head = document.head().ok_or(Error::MissingHead)?;
+                let element = document.create_element("style")?;
+                element.set_attribute("id", &self.id)?;
+                head.append_child(&element)?;
+                self.flushed = None;
+                element
+            },
+        };
+        let style_element = element.dyn_into::<HtmlStyleElement>()
+            .map_err(|_| Error::Dom(format!("#{} isn't a <style> element", self.id)))?;
+
+        // the rules are inserted and deleted one by one only while the rules of every entry are known to be in the sheet,
+        // in the order of the entries, anything else makes the whole stylesheet get rewritten
+        let updated = match (self.flushed.take(), Self::sheet(&style_element)) {
+            (Some(flushed), Some(sheet)) => self.update_rules(&sheet, flushed).is_ok(),
+            _ => false,
+        };
+        if !updated {
+            style_element.set_text_content(Some(&self.to_css()));
+            // the rules of the entries can only be told apart if the browser kept every one of them
+            let flushed = self.entries.iter()
+                .map(|(key, css)| (key.clone(), css.clone(), split::split_rules(css).len()))
+                .collect::<Vec<(String, String, usize)>>();
+            let rule_count = flushed.iter().map(|(_, _, rule_count)| rule_count).sum::<usize>();
+            let kept_all = Self::sheet(&style_element).is_some_and(|sheet| Self::rule_count(&sheet).ok() == Some(rule_count));
+            self.flushed = kept_all.then_some(flushed);
+        }
+        Ok(())
+    }
+
+    fn sheet(style_element: &HtmlStyleElement) -> Option<CssStyleSheet> {
+        style_element.sheet().and_then(|sheet| sheet.dyn_into::<CssStyleSheet>().ok())
+    }
+
+    fn rule_count(sheet: &CssStyleSheet) -> Result<usize, Error> {
+        Ok(sheet.css_rules()?.length() as usize)
+    }
+
+    // deletes the rules of the entries that changed or were removed since the last flush, then inserts the new ones
+    // in between the entries that were kept, fails before touching the sheet if the kept ones changed their order
+    fn update_rules(&mut self, sheet: &CssStyleSheet, flushed: Vec<(String, String, usize)>) -> Result<(), Error> {
+        if Self::rule_count(sheet)? != flushed.iter().map(|(_, _, rule_count)| rule_count).sum::<usize>() {
+            return Err(Error::Dom("the rules of the stylesheet were changed from outside".to_owned()));
+        }
+
+        let is_kept = |key: &str, css: &str| {
+            self.entries.iter().any(|(entry_key, entry_css)| entry_key == key && entry_css == css)
+                && flushed.iter().any(|(flushed_key, flushed_css, _)| flushed_key == key && flushed_css == css)
+        };
+        let kept_in_sheet = flushed.iter().filter(|(key, css, _)| is_kept(key, css)).map(|(key, _, _)| key);
+        let kept_in_entries = self.entries.iter().filter(|(key, css)| is_kept(key, css)).map(|(key, _)| key);
+        if !kept_in_sheet.eq(kept_in_entries) {
+            return Err(Error::Dom("the rules of the stylesheet were moved".to_owned()));
+        }
+
+        let mut index = 0;
+        let mut kept = Vec::new();
+        for (key, css, rule_count) in flushed {
+            if self.entries.iter().any(|(entry_key, entry_css)| *entry_key == key && *entry_css == css) {
+                index += rule_count;
+                kept.push((key, css, rule_count));
+            } else {
+                for _ in 0..rule_count {
+                    sheet.delete_rule(index as u32)?;
+                }
+            }
+        }
+
+        // the kept entries are in the same order as in the entries, so the new ones go in between them
+        let mut index = 0;
+        let mut kept = kept.into_iter().peekable();
+        let mut flushed = Vec::new();
+        for (key, css) in &self.entries {
+            if let Some(entry) = kept.next_if(|(kept_key, _, _)| kept_key == key) {
+                index += entry.2;
+                flushed.push(entry);
+                continue;
+            }
+            // the rules the browser actually inserted are counted, a rule it rejects throws and isn't inserted,
+            // like it would be left out of the text of the <style> element
+            let mut rule_count = 0;
+            for rule in split::split_rules(css) {
+                let before = Self::rule_count(sheet)?;
+                let _ = sheet.insert_rule_with_index(rule, index as u32);
+                let inserted = Self::rule_count(sheet)? - before;
+                index += inserted;
+                rule_count += inserted;
+            }
+            flushed.push((key.clone(), css.clone(), rule_count));
+        }
+
+        self.flushed = Some(flushed);
+        Ok(())
+    }
+}
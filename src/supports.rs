@@ -0,0 +1,92 @@
+//! Feature queries that rules are wrapped in, like `@supports (display: grid) { ... }`.
+
+use std::fmt;
+use std::ops::Not;
+
+/// A condition on what the browser supports, written as `(display: grid) and (not selector(:has(a)))`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SupportsCondition {
+    // `(display: grid)`
+    Declaration(String, String),
+    // `selector(:has(a))`
+    Selector(String),
+    Not(Box<SupportsCondition>),
+    And(Vec<SupportsCondition>),
+    Or(Vec<SupportsCondition>),
+    // any other condition, written as-is
+    Raw(String),
+}
+
+impl SupportsCondition {
+    pub fn declaration(property: &str, value: &str) -> Self {
+        SupportsCondition::Declaration(property.to_owned(), value.to_owned())
+    }
+
+    pub fn selector(selector: &str) -> Self {
+        SupportsCondition::Selector(selector.to_owned())
+    }
+
+    pub fn raw(condition: &str) -> Self {
+        SupportsCondition::Raw(condition.to_owned())
+    }
+
+    pub fn and(self, other: SupportsCondition) -> Self {
+        match self {
+            SupportsCondition::And(mut conditions) => {
+                conditions.push(other);
+                SupportsCondition::And(conditions)
+            },
+            condition => SupportsCondition::And(vec![condition, other]),
+        }
+    }
+
+    pub fn or(self, other: SupportsCondition) -> Self {
+        match self {
+            SupportsCondition::Or(mut conditions) => {
+                conditions.push(other);
+                SupportsCondition::Or(conditions)
+            },
+            condition => SupportsCondition::Or(vec![condition, other]),
+        }
+    }
+
+    // the rules wrapped in an @supports block for this condition
+    pub fn wrap(&self, rules: &str) -> String {
+        format!("@supports {} {{ {} }}", self, rules)
+    }
+
+    // conditions combined with and, or and not have to be put in parentheses when they're combined again,
+    // and so do raw conditions, which might be combined already
+    fn in_parens(&self) -> String {
+        match self {
+            SupportsCondition::Not(_) | SupportsCondition::And(_) | SupportsCondition::Or(_) | SupportsCondition::Raw(_) => format!("({})", self),
+            condition => condition.to_string(),
+        }
+    }
+}
+
+// the browser doesn't support this (!SupportsCondition::selector(":has(a)"))
+impl Not for SupportsCondition {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        SupportsCondition::Not(Box::new(self))
+    }
+}
+
+impl fmt::Display for SupportsCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SupportsCondition::Declaration(property, value) => write!(f, "({}: {})", property, value),
+            SupportsCondition::Selector(selector) => write!(f, "selector({})", selector),
+            SupportsCondition::Not(condition) => write!(f, "not {}", condition.in_parens()),
+            SupportsCondition::And(conditions) => {
+                write!(f, "{}", conditions.iter().map(SupportsCondition::in_parens).collect::<Vec<String>>().join(" and "))
+            },
+            SupportsCondition::Or(conditions) => {
+                write!(f, "{}", conditions.iter().map(SupportsCondition::in_parens).collect::<Vec<String>>().join(" or "))
+            },
+            SupportsCondition::Raw(condition) => write!(f, "{}", condition.trim()),
+        }
+    }
+}
@@ -0,0 +1,351 @@
+//! A tokenizer following [CSS Syntax Level 3](https://www.w3.org/TR/css-syntax-3/#tokenization).
+//! Tokens only hold their byte range in the css, so every part of a value that is read
+//! can still be pointed at in errors. Unlike the spec, comments are kept as tokens too.
+
+// the kinds of tokens of css syntax level 3, numeric tokens hold their number
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TokenKind {
+    Ident,
+    // a name directly followed by `(`, the token includes the `(`
+    Function,
+    AtKeyword,
+    Hash,
+    // a quoted string, the token includes the quotes
+    String,
+    // a string with an unescaped newline in it
+    BadString,
+    // an unquoted url(...), the token includes `url(` and `)`
+    Url,
+    BadUrl,
+    Delim(char),
+    Number(f64),
+    Percentage(f64),
+    // a number with a unit, the unit starts where the number ends
+    Dimension(f64),
+    Whitespace,
+    Comment,
+    Cdo,
+    Cdc,
+    Colon,
+    Semicolon,
+    Comma,
+    OpenSquare,
+    CloseSquare,
+    OpenParen,
+    CloseParen,
+    OpenCurly,
+    CloseCurly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    // byte offsets into the css the token was read from
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Token {
+    // the css text of the token
+    pub fn text<'a>(&self, css: &'a str) -> &'a str {
+        &css[self.start..self.end]
+    }
+
+    // whitespace and comments, which only separate the tokens around them
+    pub fn is_trivia(&self) -> bool {
+        matches!(self.kind, TokenKind::Whitespace | TokenKind::Comment)
+    }
+
+    // tokens that open a block, which ends at the matching closing token
+    pub fn opens_block(&self) -> bool {
+        matches!(self.kind, TokenKind::Function | TokenKind::OpenParen | TokenKind::OpenSquare | TokenKind::OpenCurly)
+    }
+
+    pub fn closes_block(&self) -> bool {
+        matches!(self.kind, TokenKind::CloseParen | TokenKind::CloseSquare | TokenKind::CloseCurly)
+    }
+}
+
+pub fn tokenize(css: &str) -> Vec<Token> {
+    Tokenizer::new(css).collect()
+}
+
+pub struct Tokenizer<'a> {
+    css: &'a str,
+    position: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(css: &'a str) -> Self {
+        Tokenizer { css, position: 0 }
+    }
+
+    // the code point n code points after the current one
+    fn peek(&self, n: usize) -> Option<char> {
+        self.css[self.position..].chars().nth(n)
+    }
+
+    fn consume(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn starts_with(&self, text: &str) -> bool {
+        self.css[self.position..].starts_with(text)
+    }
+
+    fn consume_while(&mut self, predicate: impl Fn(char) -> bool) {
+        while self.peek(0).is_some_and(&predicate) {
+            self.consume();
+        }
+    }
+
+    // https://www.w3.org/TR/css-syntax-3/#consume-token
+    fn consume_token(&mut self) -> Option<TokenKind> {
+        if self.starts_with("/*") {
+            self.position = match self.css[self.position + 2..].find("*/") {
+                Some(end) => self.position + 2 + end + 2,
+                None => self.css.len(),
+            };
+            return Some(TokenKind::Comment);
+        }
+
+        let (c, next, after) = (self.peek(0)?, self.peek(1), self.peek(2));
+        let kind = match c {
+            c if is_whitespace(c) => {
+                self.consume_while(is_whitespace);
+                TokenKind::Whitespace
+            },
+            '"' | '\'' => self.consume_string(),
+            '#' if next.is_some_and(is_name) || starts_escape(next, after) => {
+                self.consume();
+                self.consume_name();
+                TokenKind::Hash
+            },
+            '(' => { self.consume(); TokenKind::OpenParen },
+            ')' => { self.consume(); TokenKind::CloseParen },
+            '[' => { self.consume(); TokenKind::OpenSquare },
+            ']' => { self.consume(); TokenKind::CloseSquare },
+            '{' => { self.consume(); TokenKind::OpenCurly },
+            '}' => { self.consume(); TokenKind::CloseCurly },
+            ',' => { self.consume(); TokenKind::Comma },
+            ':' => { self.consume(); TokenKind::Colon },
+            ';' => { self.consume(); TokenKind::Semicolon },
+            c if starts_number(c, next, after) => self.consume_numeric(),
+            '-' if next == Some('-') && after == Some('>') => {
+                self.position += 3;
+                TokenKind::Cdc
+            },
+            c if starts_ident(c, next, after) => self.consume_ident_like(),
+            '<' if self.starts_with("<!--") => {
+                self.position += 4;
+                TokenKind::Cdo
+            },
+            '@' if next.is_some_and(|next| starts_ident(next, after, self.peek(3))) => {
+                self.consume();
+                self.consume_name();
+                TokenKind::AtKeyword
+            },
+            c => {
+                self.consume();
+                TokenKind::Delim(c)
+            },
+        };
+        Some(kind)
+    }
+
+    // https://www.w3.org/TR/css-syntax-3/#consume-string-token
+    fn consume_string(&mut self) -> TokenKind {
+        let quote = self.consume();
+        loop {
+            match self.peek(0) {
+                None => return TokenKind::String,
+                c if c == quote => {
+                    self.consume();
+                    return TokenKind::String;
+                },
+                Some(c) if is_newline(c) => return TokenKind::BadString,
+                Some('\\') => {
+                    self.consume();
+                    match self.peek(0) {
+                        None => {},
+                        Some(c) if is_newline(c) => { self.consume(); },
+                        Some(_) => self.consume_escape(),
+                    }
+                },
+                Some(_) => { self.consume(); },
+            }
+        }
+    }
+
+    // https://www.w3.org/TR/css-syntax-3/#consume-numeric-token
+    fn consume_numeric(&mut self) -> TokenKind {
+        let start = self.position;
+        if matches!(self.peek(0), Some('+') | Some('-')) {
+            self.consume();
+        }
+        self.consume_while(|c| c.is_ascii_digit());
+        if self.peek(0) == Some('.') && self.peek(1).is_some_and(|c| c.is_ascii_digit()) {
+            self.consume();
+            self.consume_while(|c| c.is_ascii_digit());
+        }
+        if matches!(self.peek(0), Some('e') | Some('E')) {
+            let sign = if matches!(self.peek(1), Some('+') | Some('-')) { 1 } else { 0 };
+            if self.peek(1 + sign).is_some_and(|c| c.is_ascii_digit()) {
+                self.position += 1 + sign;
+                self.consume_while(|c| c.is_ascii_digit());
+            }
+        }
+        let number = self.css[start..self.position].parse::<f64>().unwrap_or_default();
+
+        match (self.peek(0), self.peek(1), self.peek(2)) {
+            (Some(c), next, after) if starts_ident(c, next, after) => {
+                self.consume_name();
+                TokenKind::Dimension(number)
+            },
+            (Some('%'), _, _) => {
+                self.consume();
+                TokenKind::Percentage(number)
+            },
+            _ => TokenKind::Number(number),
+        }
+    }
+
+    // https://www.w3.org/TR/css-syntax-3/#consume-ident-like-token
+    fn consume_ident_like(&mut self) -> TokenKind {
+        let start = self.position;
+        self.consume_name();
+        if self.peek(0) != Some('(') {
+            return TokenKind::Ident;
+        }
+        self.consume();
+
+        // url( with a quoted argument is a function like any other
+        if self.css[start..self.position - 1].eq_ignore_ascii_case("url") {
+            let arg = self.css[self.position..].trim_start_matches(is_whitespace);
+            if !arg.starts_with('"') && !arg.starts_with('\'') {
+                return self.consume_url();
+            }
+        }
+        TokenKind::Function
+    }
+
+    // https://www.w3.org/TR/css-syntax-3/#consume-url-token
+    fn consume_url(&mut self) -> TokenKind {
+        self.consume_while(is_whitespace);
+        loop {
+            match self.consume() {
+                None | Some(')') => return TokenKind::Url,
+                Some(c) if is_whitespace(c) => {
+                    self.consume_while(is_whitespace);
+                    if matches!(self.peek(0), None | Some(')')) {
+                        self.consume();
+                        return TokenKind::Url;
+                    }
+                    return self.consume_bad_url();
+                },
+                Some('"') | Some('\'') | Some('(') => return self.consume_bad_url(),
+                Some('\\') if self.peek(0).is_some_and(|c| !is_newline(c)) => self.consume_escape(),
+                Some('\\') => return self.consume_bad_url(),
+                Some(c) if is_non_printable(c) => return self.consume_bad_url(),
+                Some(_) => {},
+            }
+        }
+    }
+
+    // https://www.w3.org/TR/css-syntax-3/#consume-remnants-of-bad-url
+    fn consume_bad_url(&mut self) -> TokenKind {
+        loop {
+            match self.consume() {
+                None | Some(')') => return TokenKind::BadUrl,
+                Some('\\') if self.peek(0).is_some_and(|c| !is_newline(c)) => self.consume_escape(),
+                Some(_) => {},
+            }
+        }
+    }
+
+    // https://www.w3.org/TR/css-syntax-3/#consume-name
+    fn consume_name(&mut self) {
+        loop {
+            match self.peek(0) {
+                Some(c) if is_name(c) => { self.consume(); },
+                Some('\\') if starts_escape(Some('\\'), self.peek(1)) => {
+                    self.consume();
+                    self.consume_escape();
+                },
+                _ => return,
+            }
+        }
+    }
+
+    // https://www.w3.org/TR/css-syntax-3/#consume-escaped-code-point, after the backslash
+    fn consume_escape(&mut self) {
+        match self.peek(0) {
+            Some(c) if c.is_ascii_hexdigit() => {
+                for _ in 0..6 {
+                    if !self.peek(0).is_some_and(|c| c.is_ascii_hexdigit()) { break; }
+                    self.consume();
+                }
+                if self.peek(0).is_some_and(is_whitespace) {
+                    self.consume();
+                }
+            },
+            _ => { self.consume(); },
+        }
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        let start = self.position;
+        let kind = self.consume_token()?;
+        Some(Token { kind, start, end: self.position })
+    }
+}
+
+fn is_newline(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\x0C')
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t') || is_newline(c)
+}
+
+fn is_non_printable(c: char) -> bool {
+    matches!(c, '\x00'..='\x08' | '\x0B' | '\x0E'..='\x1F' | '\x7F')
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || !c.is_ascii()
+}
+
+fn is_name(c: char) -> bool {
+    is_name_start(c) || c.is_ascii_digit() || c == '-'
+}
+
+// https://www.w3.org/TR/css-syntax-3/#starts-with-a-valid-escape
+fn starts_escape(first: Option<char>, second: Option<char>) -> bool {
+    first == Some('\\') && second.is_some_and(|c| !is_newline(c))
+}
+
+// https://www.w3.org/TR/css-syntax-3/#would-start-an-identifier
+fn starts_ident(first: char, second: Option<char>, third: Option<char>) -> bool {
+    match first {
+        '-' => second.is_some_and(|c| is_name_start(c) || c == '-') || starts_escape(second, third),
+        '\\' => starts_escape(Some(first), second),
+        c => is_name_start(c),
+    }
+}
+
+// https://www.w3.org/TR/css-syntax-3/#starts-with-a-number
+fn starts_number(first: char, second: Option<char>, third: Option<char>) -> bool {
+    let digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_digit());
+    match first {
+        '+' | '-' => digit(second) || (second == Some('.') && digit(third)),
+        '.' => digit(second),
+        c => c.is_ascii_digit(),
+    }
+}
@@ -0,0 +1,11 @@
+use super::dimension::dimension;
+
+dimension! {
+    /// A css `<angle>` like `45deg` or `0.25turn`.
+    Angle(AngleUnit, default = Deg, unitless_zero = true, "an angle", "45deg") {
+        Deg => deg("deg", Some(1.0)),
+        Grad => grad("grad", Some(0.9)),
+        Rad => rad("rad", Some(180.0 / std::f64::consts::PI)),
+        Turn => turn("turn", Some(360.0)),
+    }
+}
@@ -0,0 +1,348 @@
+use super::{ CssValue, Angle, AngleUnit, split_number };
+use super::named_colors::NAMED_COLORS;
+use crate::error::{ Error, offset_in };
+use crate::split;
+use bevy_reflect::{ Reflect, FromReflect };
+use std::fmt;
+use std::str::FromStr;
+
+// how a Color is written in css, colors are always written in the format they hold, whatever format they were read from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColorFormat {
+    // #rrggbb or #rrggbbaa
+    #[default]
+    Hex,
+    // rgb(r, g, b) or rgba(r, g, b, a)
+    Rgb,
+    // hsl(h, s%, l%) or hsla(h, s%, l%, a)
+    Hsl,
+    // hwb(h w% b%) or hwb(h w% b% / a)
+    Hwb,
+}
+
+/// A css `<color>` in the srgb color space, read from hex, `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()` and named colors.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, FromReflect)]
+#[reflect_value(PartialEq, Debug)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    // 0 is fully transparent, 1 fully opaque
+    pub alpha: f64,
+    pub format: ColorFormat,
+}
+
+impl Color {
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::rgba(red, green, blue, 1.0)
+    }
+
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f64) -> Self {
+        Self { red, green, blue, alpha: alpha.clamp(0.0, 1.0), format: ColorFormat::default() }
+    }
+
+    // hue in degrees, saturation and lightness from 0 to 1
+    pub fn hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self::hsla(hue, saturation, lightness, 1.0)
+    }
+
+    pub fn hsla(hue: f64, saturation: f64, lightness: f64, alpha: f64) -> Self {
+        let (red, green, blue) = hsl_to_rgb(hue, saturation.clamp(0.0, 1.0), lightness.clamp(0.0, 1.0));
+        Self::from_fractions(red, green, blue, alpha)
+    }
+
+    // hue in degrees, whiteness and blackness from 0 to 1
+    pub fn hwb(hue: f64, whiteness: f64, blackness: f64) -> Self {
+        let (red, green, blue) = hwb_to_rgb(hue, whiteness.clamp(0.0, 1.0), blackness.clamp(0.0, 1.0));
+        Self::from_fractions(red, green, blue, 1.0)
+    }
+
+    // the same color written in another format
+    pub fn with_format(self, format: ColorFormat) -> Self {
+        Self { format, ..self }
+    }
+
+    // hue in degrees, saturation and lightness from 0 to 1
+    pub fn to_hsl(self) -> (f64, f64, f64) {
+        let (red, green, blue) = self.fractions();
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min { 0.0 } else { (max - min) / (1.0 - (2.0 * lightness - 1.0).abs()) };
+        (hue(red, green, blue), saturation, lightness)
+    }
+
+    // hue in degrees, whiteness and blackness from 0 to 1
+    pub fn to_hwb(self) -> (f64, f64, f64) {
+        let (red, green, blue) = self.fractions();
+        (hue(red, green, blue), red.min(green).min(blue), 1.0 - red.max(green).max(blue))
+    }
+
+    // the color between this one (weight 0) and the other one (weight 1), keeps the format of this one
+    pub fn mix(self, other: Color, weight: f64) -> Self {
+        let weight = weight.clamp(0.0, 1.0);
+        let channel = |this: u8, other: u8| (this as f64 * (1.0 - weight) + other as f64 * weight).round() as u8;
+        Self {
+            red: channel(self.red, other.red),
+            green: channel(self.green, other.green),
+            blue: channel(self.blue, other.blue),
+            alpha: self.alpha * (1.0 - weight) + other.alpha * weight,
+            format: self.format,
+        }
+    }
+
+    // raises the hsl lightness by the amount (0.1 for 10%)
+    pub fn lighten(self, amount: f64) -> Self {
+        let (hue, saturation, lightness) = self.to_hsl();
+        Self { format: self.format, ..Self::hsla(hue, saturation, lightness + amount, self.alpha) }
+    }
+
+    // lowers the hsl lightness by the amount (0.1 for 10%)
+    pub fn darken(self, amount: f64) -> Self {
+        self.lighten(-amount)
+    }
+
+    // the relative luminance as defined by the WCAG, 0 for black and 1 for white
+    pub fn luminance(self) -> f64 {
+        let linear = |channel: f64| if channel <= 0.04045 { channel / 12.92 } else { ((channel + 0.055) / 1.055).powf(2.4) };
+        let (red, green, blue) = self.fractions();
+        0.2126 * linear(red) + 0.7152 * linear(green) + 0.0722 * linear(blue)
+    }
+
+    // the WCAG contrast ratio between the two colors, from 1 (none) to 21 (black on white)
+    pub fn contrast(self, other: Color) -> f64 {
+        let (this, other) = (self.luminance(), other.luminance());
+        (this.max(other) + 0.05) / (this.min(other) + 0.05)
+    }
+
+    fn fractions(self) -> (f64, f64, f64) {
+        (self.red as f64 / 255.0, self.green as f64 / 255.0, self.blue as f64 / 255.0)
+    }
+
+    fn from_fractions(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        let channel = |fraction: f64| (fraction.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self::rgba(channel(red), channel(green), channel(blue), alpha)
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self::rgb(0, 0, 0)
+    }
+}
+
+// the hue in degrees of a color with channels from 0 to 1
+fn hue(red: f64, green: f64, blue: f64) -> f64 {
+    let max = red.max(green).max(blue);
+    let delta = max - red.min(green).min(blue);
+    if delta == 0.0 {
+        return 0.0;
+    }
+    let sixths = if max == red {
+        ((green - blue) / delta).rem_euclid(6.0)
+    } else if max == green {
+        (blue - red) / delta + 2.0
+    } else {
+        (red - green) / delta + 4.0
+    };
+    sixths * 60.0
+}
+
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (f64, f64, f64) {
+    let hue = hue.rem_euclid(360.0);
+    let a = saturation * lightness.min(1.0 - lightness);
+    let f = |n: f64| {
+        let k = (n + hue / 30.0) % 12.0;
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    (f(0.0), f(8.0), f(4.0))
+}
+
+fn hwb_to_rgb(hue: f64, whiteness: f64, blackness: f64) -> (f64, f64, f64) {
+    if whiteness + blackness >= 1.0 {
+        let gray = whiteness / (whiteness + blackness);
+        return (gray, gray, gray);
+    }
+    let (red, green, blue) = hsl_to_rgb(hue, 1.0, 0.5);
+    let scale = |channel: f64| channel * (1.0 - whiteness - blackness) + whiteness;
+    (scale(red), scale(green), scale(blue))
+}
+
+// rounds to at most the given number of decimals, so the css doesn't end up with 33.33333333333333%
+fn round(number: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (number * factor).round() / factor
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let opaque = self.alpha >= 1.0;
+        let alpha = round(self.alpha, 3);
+        match self.format {
+            ColorFormat::Hex => {
+                write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)?;
+                if !opaque {
+                    write!(f, "{:02x}", (self.alpha * 255.0).round() as u8)?;
+                }
+                Ok(())
+            },
+            ColorFormat::Rgb if opaque => write!(f, "rgb({}, {}, {})", self.red, self.green, self.blue),
+            ColorFormat::Rgb => write!(f, "rgba({}, {}, {}, {})", self.red, self.green, self.blue, alpha),
+            ColorFormat::Hsl => {
+                let (hue, saturation, lightness) = self.to_hsl();
+                let (hue, saturation, lightness) = (round(hue, 1), round(saturation * 100.0, 1), round(lightness * 100.0, 1));
+                if opaque {
+                    write!(f, "hsl({}, {}%, {}%)", hue, saturation, lightness)
+                } else {
+                    write!(f, "hsla({}, {}%, {}%, {})", hue, saturation, lightness, alpha)
+                }
+            },
+            ColorFormat::Hwb => {
+                let (hue, whiteness, blackness) = self.to_hwb();
+                write!(f, "hwb({} {}% {}%", round(hue, 1), round(whiteness * 100.0, 1), round(blackness * 100.0, 1))?;
+                if !opaque {
+                    write!(f, " / {}", alpha)?;
+                }
+                f.write_str(")")
+            },
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = Error;
+
+    fn from_str(css: &str) -> Result<Self, Self::Err> {
+        let trimmed = split::trim(css);
+        let offset = offset_in(css, trimmed);
+
+        if let Some(hex) = trimmed.strip_prefix('#') {
+            return from_hex(hex)
+                .ok_or_else(|| Error::parse(offset, format!("expected a hex color like `#ff8800`, found `{}`", trimmed)));
+        }
+
+        let function = match split::function(trimmed) {
+            Some(function) => function,
+            None => {
+                if trimmed.eq_ignore_ascii_case("transparent") {
+                    return Ok(Color::rgba(0, 0, 0, 0.0));
+                }
+                return NAMED_COLORS.iter()
+                    .find(|(name, _)| trimmed.eq_ignore_ascii_case(name))
+                    .map(|(_, rgb)| Color::rgb((rgb >> 16) as u8, (rgb >> 8) as u8, *rgb as u8))
+                    .ok_or_else(|| Error::parse(offset, format!("expected a color, found `{}`", trimmed)));
+            },
+        };
+        let name = function.name.to_ascii_lowercase();
+        let args = function.args;
+
+        // legacy syntax separates all arguments with commas, modern syntax uses spaces and puts the alpha behind a slash
+        let (channels, alpha) = match split::split_slash(args) {
+            Some((channels, alpha)) => (channels, Some(alpha)),
+            None => (args, None),
+        };
+        let mut values = match split::split_commas(channels) {
+            values if values.len() > 1 => values,
+            _ => split::split_whitespace(channels, usize::MAX),
+        };
+        let alpha = match alpha {
+            Some(alpha) => Some(alpha),
+            None if values.len() == 4 => values.pop(),
+            None => None,
+        };
+        if values.len() != 3 {
+            let message = format!("expected 3 values and an optional alpha in `{}()`, found `{}`", name, split::trim(args));
+            return Err(Error::parse(offset_in(css, args), message));
+        }
+
+        let expected = |what: &str, arg: &str| {
+            Error::parse(offset_in(css, arg), format!("expected {} in `{}()`, found `{}`", what, name, arg))
+        };
+        let alpha = match alpha {
+            Some(arg) => parse_alpha(arg).ok_or_else(|| expected("an alpha like `0.5` or `50%`", arg))?,
+            None => 1.0,
+        };
+
+        match name.as_str() {
+            "rgb" | "rgba" => {
+                let mut channels = [0u8; 3];
+                for (channel, arg) in channels.iter_mut().zip(&values) {
+                    *channel = parse_channel(arg).ok_or_else(|| expected("a number from 0 to 255 or a percentage", arg))?;
+                }
+                Ok(Color::rgba(channels[0], channels[1], channels[2], alpha))
+            },
+            "hsl" | "hsla" | "hwb" => {
+                let hue = parse_hue(values[0]).ok_or_else(|| expected("a hue like `120` or `120deg`", values[0]))?;
+                let first = parse_fraction(values[1]).ok_or_else(|| expected("a percentage", values[1]))?;
+                let second = parse_fraction(values[2]).ok_or_else(|| expected("a percentage", values[2]))?;
+                if name == "hwb" {
+                    Ok(Color { alpha, ..Color::hwb(hue, first, second) })
+                } else {
+                    Ok(Color::hsla(hue, first, second, alpha))
+                }
+            },
+            _ => Err(Error::parse(offset, format!("expected one of `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()`, found `{}()`", name))),
+        }
+    }
+}
+
+// #rgb, #rgba, #rrggbb or #rrggbbaa without the #
+fn from_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok().map(|digit| digit * 17);
+    let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    let (red, green, blue, alpha) = match hex.len() {
+        3 => (digit(0)?, digit(1)?, digit(2)?, 255),
+        4 => (digit(0)?, digit(1)?, digit(2)?, digit(3)?),
+        6 => (pair(0)?, pair(2)?, pair(4)?, 255),
+        8 => (pair(0)?, pair(2)?, pair(4)?, pair(6)?),
+        _ => return None,
+    };
+    Some(Color::rgba(red, green, blue, alpha as f64 / 255.0))
+}
+
+fn parse_channel(arg: &str) -> Option<u8> {
+    let channel = match split_number(arg)? {
+        (number, "") => number,
+        (number, "%") => number / 100.0 * 255.0,
+        _ => return None,
+    };
+    Some(channel.clamp(0.0, 255.0).round() as u8)
+}
+
+fn parse_alpha(arg: &str) -> Option<f64> {
+    match split_number(arg)? {
+        (number, "") => Some(number.clamp(0.0, 1.0)),
+        (number, "%") => Some((number / 100.0).clamp(0.0, 1.0)),
+        _ => None,
+    }
+}
+
+fn parse_fraction(arg: &str) -> Option<f64> {
+    match split_number(arg)? {
+        (number, "%") => Some((number / 100.0).clamp(0.0, 1.0)),
+        _ => None,
+    }
+}
+
+// in degrees, a hue without unit is in degrees too
+fn parse_hue(arg: &str) -> Option<f64> {
+    match split_number(arg)? {
+        (number, "") => Some(number),
+        _ => Some(arg.parse::<Angle>().ok()?.to(AngleUnit::Deg)?.value),
+    }
+}
+
+impl CssValue for Color {
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    // keeps the format of the color, so it is written the same way no matter how the css wrote it
+    fn set_from_css(&mut self, css: &str) -> Result<(), Error> {
+        *self = css.parse::<Color>()?.with_format(self.format);
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+// a dimension is a number with a unit (i.e. 10px, 45deg, 200ms), this macro generates the value type,
+// its unit enum, the conversion to and from css and the arithmetic for each kind of dimension,
+// adding and subtracting is only done by checked_add and checked_sub since relative units need the browser
+
+macro_rules! dimension {
+    (
+        $(#[$meta:meta])*
+        $name:ident($unit:ident, default = $default:ident, unitless_zero = $unitless_zero:literal, $what:literal, $example:literal) {
+            $( $(#[$unit_meta:meta])* $variant:ident => $constructor:ident($suffix:literal, $factor:expr), )*
+        }
+    ) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $unit {
+            $( $(#[$unit_meta])* $variant, )*
+        }
+
+        impl $unit {
+            // how the unit is written in css
+            pub fn suffix(self) -> &'static str {
+                match self {
+                    $( $unit::$variant => $suffix, )*
+                }
+            }
+
+            // the unit written like this in css, ignoring ascii case like the browser does
+            pub fn from_suffix(suffix: &str) -> Option<Self> {
+                $(
+                    if suffix.eq_ignore_ascii_case($suffix) {
+                        return Some($unit::$variant);
+                    }
+                )*
+                None
+            }
+
+            // the size of the unit in the first unit of the enum,
+            // None for units that only the browser can resolve (i.e. em depends on the font size)
+            fn factor(self) -> Option<f64> {
+                match self {
+                    $( $unit::$variant => $factor, )*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $unit {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.suffix())
+            }
+        }
+
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, bevy_reflect::Reflect, bevy_reflect::FromReflect)]
+        #[reflect_value(PartialEq, Debug)]
+        pub struct $name {
+            pub value: f64,
+            pub unit: $unit,
+        }
+
+        impl $name {
+            pub fn new(value: f64, unit: $unit) -> Self {
+                Self { value, unit }
+            }
+
+            $(
+                pub fn $constructor(value: f64) -> Self {
+                    Self::new(value, $unit::$variant)
+                }
+            )*
+
+            // the same amount in another unit, None if the browser is needed to convert between the two
+            pub fn to(self, unit: $unit) -> Option<Self> {
+                if self.unit == unit {
+                    return Some(self);
+                }
+                Some(Self::new(self.value * self.unit.factor()? / unit.factor()?, unit))
+            }
+
+            // adds the other value in the unit of this one, None if they can't be converted into each other
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                Some(Self::new(self.value + rhs.to(self.unit)?.value, self.unit))
+            }
+
+            // subtracts the other value in the unit of this one, None if they can't be converted into each other
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                Some(Self::new(self.value - rhs.to(self.unit)?.value, self.unit))
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new(0.0, $unit::$default)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}{}", self.value, self.unit)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::Error;
+
+            fn from_str(css: &str) -> Result<Self, Self::Err> {
+                let (value, unit) = crate::values::parse_dimension(css, $what, $example, $unit::from_suffix, $unitless_zero.then_some($unit::$default))?;
+                Ok(Self::new(value, unit))
+            }
+        }
+
+        impl crate::values::CssValue for $name {
+            fn to_css(&self) -> String {
+                self.to_string()
+            }
+
+            fn set_from_css(&mut self, css: &str) -> Result<(), crate::Error> {
+                *self = css.parse()?;
+                Ok(())
+            }
+        }
+
+        impl std::ops::Mul<f64> for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: f64) -> Self {
+                Self::new(self.value * rhs, self.unit)
+            }
+        }
+
+        impl std::ops::Div<f64> for $name {
+            type Output = Self;
+
+            fn div(self, rhs: f64) -> Self {
+                Self::new(self.value / rhs, self.unit)
+            }
+        }
+
+        impl std::ops::Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::new(-self.value, self.unit)
+            }
+        }
+    };
+}
+
+pub(crate) use dimension;
@@ -0,0 +1,9 @@
+use super::dimension::dimension;
+
+dimension! {
+    /// A css `<frequency>` like `440hz`.
+    Frequency(FrequencyUnit, default = Hz, unitless_zero = false, "a frequency", "440hz") {
+        Hz => hz("hz", Some(1.0)),
+        KHz => khz("khz", Some(1000.0)),
+    }
+}
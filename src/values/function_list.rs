@@ -0,0 +1,78 @@
+use bevy_reflect::{ Reflect, FromReflect, Struct };
+use std::fmt;
+use std::ops::{ Deref, DerefMut };
+
+/// Css functions separated by spaces, like `filter: blur(2px) brightness(1.5) blur(4px)`.
+/// Every function is a variant of the enum `T` with the arguments as its fields, so functions
+/// can repeat and appear in any order, which fields of a struct can't express.
+/// Derefs to the `Vec<T>` of functions, an empty list is written as `none`.
+#[derive(Reflect, FromReflect)]
+pub struct FunctionList<T: Reflect + FromReflect + Default> {
+    functions: Vec<T>,
+    // cloned for every function that is added while parsing
+    blank: T,
+    tag: FunctionListTag,
+}
+
+// tells the reflection code that a struct is a FunctionList
+#[doc(hidden)]
+#[derive(Reflect, FromReflect, Clone, Copy, PartialEq, Debug, Default)]
+pub struct FunctionListTag;
+
+impl<T: Reflect + FromReflect + Default> FunctionList<T> {
+    pub fn new(functions: Vec<T>) -> Self {
+        Self { functions, blank: T::default(), tag: FunctionListTag }
+    }
+
+    pub fn into_inner(self) -> Vec<T> {
+        self.functions
+    }
+}
+
+impl<T: Reflect + FromReflect + Default> Default for FunctionList<T> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<T: Reflect + FromReflect + Default> From<Vec<T>> for FunctionList<T> {
+    fn from(functions: Vec<T>) -> Self {
+        Self::new(functions)
+    }
+}
+
+impl<T: Reflect + FromReflect + Default> Deref for FunctionList<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.functions
+    }
+}
+
+impl<T: Reflect + FromReflect + Default> DerefMut for FunctionList<T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.functions
+    }
+}
+
+impl<T: Reflect + FromReflect + Default + Clone> Clone for FunctionList<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.functions.clone())
+    }
+}
+
+impl<T: Reflect + FromReflect + Default + PartialEq> PartialEq for FunctionList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.functions == other.functions
+    }
+}
+
+impl<T: Reflect + FromReflect + Default + fmt::Debug> fmt::Debug for FunctionList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FunctionList").field(&self.functions).finish()
+    }
+}
+
+pub(crate) fn is_function_list(struct_reflect: &dyn Struct) -> bool {
+    super::has_tag::<FunctionListTag>(struct_reflect)
+}
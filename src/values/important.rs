@@ -0,0 +1,64 @@
+use bevy_reflect::{ Reflect, FromReflect, Struct };
+use std::ops::{ Deref, DerefMut };
+
+/// A value that can carry the `!important` flag, like `width: 10px !important`.
+/// The flag is written after the value while it's set and is read from the end of the css,
+/// so it doesn't end up inside of the value. Derefs to the value.
+#[derive(Reflect, FromReflect, Clone, PartialEq, Debug)]
+pub struct Important<T: Reflect + FromReflect> {
+    pub value: T,
+    pub important: bool,
+    tag: ImportantTag,
+}
+
+// tells the reflection code that a struct is an Important
+#[doc(hidden)]
+#[derive(Reflect, FromReflect, Clone, Copy, PartialEq, Debug, Default)]
+pub struct ImportantTag;
+
+impl<T: Reflect + FromReflect> Important<T> {
+    // an important value
+    pub fn new(value: T) -> Self {
+        Self { value, important: true, tag: ImportantTag }
+    }
+
+    // a value without the flag
+    pub fn normal(value: T) -> Self {
+        Self { value, important: false, tag: ImportantTag }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+// the flag is only set once it's asked for, like for any other value
+impl<T: Reflect + FromReflect + Default> Default for Important<T> {
+    fn default() -> Self {
+        Self::normal(T::default())
+    }
+}
+
+impl<T: Reflect + FromReflect> From<T> for Important<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Reflect + FromReflect> Deref for Important<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Reflect + FromReflect> DerefMut for Important<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+pub(crate) fn is_important(struct_reflect: &dyn Struct) -> bool {
+    super::has_tag::<ImportantTag>(struct_reflect)
+}
@@ -0,0 +1,36 @@
+use super::dimension::dimension;
+
+dimension! {
+    /// A css `<length>` like `10px` or `1.5em`.
+    Length(LengthUnit, default = Px, unitless_zero = true, "a length", "10px") {
+        Px => px("px", Some(1.0)),
+        Cm => cm("cm", Some(96.0 / 2.54)),
+        Mm => mm("mm", Some(96.0 / 25.4)),
+        Q => q("q", Some(96.0 / 101.6)),
+        In => inches("in", Some(96.0)),
+        Pt => pt("pt", Some(96.0 / 72.0)),
+        Pc => pc("pc", Some(16.0)),
+        Em => em("em", None),
+        Rem => rem("rem", None),
+        Ex => ex("ex", None),
+        Ch => ch("ch", None),
+        Lh => lh("lh", None),
+        Rlh => rlh("rlh", None),
+        Vw => vw("vw", None),
+        Vh => vh("vh", None),
+        Vmin => vmin("vmin", None),
+        Vmax => vmax("vmax", None),
+        Svw => svw("svw", None),
+        Svh => svh("svh", None),
+        Lvw => lvw("lvw", None),
+        Lvh => lvh("lvh", None),
+        Dvw => dvw("dvw", None),
+        Dvh => dvh("dvh", None),
+        Cqw => cqw("cqw", None),
+        Cqh => cqh("cqh", None),
+        Cqi => cqi("cqi", None),
+        Cqb => cqb("cqb", None),
+        Cqmin => cqmin("cqmin", None),
+        Cqmax => cqmax("cqmax", None),
+    }
+}
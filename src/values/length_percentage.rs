@@ -0,0 +1,63 @@
+use super::{ CssValue, Length, Percentage };
+use crate::error::Error;
+use bevy_reflect::{ Reflect, FromReflect };
+use std::fmt;
+use std::str::FromStr;
+
+/// A css `<length-percentage>`, either a `Length` like `10px` or a `Percentage` like `50%`.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, FromReflect)]
+#[reflect_value(PartialEq, Debug)]
+pub enum LengthPercentage {
+    Length(Length),
+    Percentage(Percentage),
+}
+
+impl Default for LengthPercentage {
+    fn default() -> Self {
+        LengthPercentage::Length(Length::default())
+    }
+}
+
+impl From<Length> for LengthPercentage {
+    fn from(length: Length) -> Self {
+        LengthPercentage::Length(length)
+    }
+}
+
+impl From<Percentage> for LengthPercentage {
+    fn from(percentage: Percentage) -> Self {
+        LengthPercentage::Percentage(percentage)
+    }
+}
+
+impl fmt::Display for LengthPercentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LengthPercentage::Length(length) => length.fmt(f),
+            LengthPercentage::Percentage(percentage) => percentage.fmt(f),
+        }
+    }
+}
+
+impl FromStr for LengthPercentage {
+    type Err = Error;
+
+    fn from_str(css: &str) -> Result<Self, Self::Err> {
+        if css.trim_end().ends_with('%') {
+            Ok(LengthPercentage::Percentage(css.parse()?))
+        } else {
+            Ok(LengthPercentage::Length(css.parse()?))
+        }
+    }
+}
+
+impl CssValue for LengthPercentage {
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn set_from_css(&mut self, css: &str) -> Result<(), Error> {
+        *self = css.parse()?;
+        Ok(())
+    }
+}
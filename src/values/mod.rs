@@ -0,0 +1,160 @@
+//! Typed css values like lengths and colors. Fields of these types are rendered and parsed as a whole
+//! (i.e. `Length::px(10.0)` <-> `10px`) and can be calculated with, like numbers.
+
+mod dimension;
+mod length;
+mod angle;
+mod time;
+mod frequency;
+mod resolution;
+mod percentage;
+mod length_percentage;
+mod color;
+mod named_colors;
+mod transform;
+mod function_list;
+mod important;
+mod var;
+pub use length::{ Length, LengthUnit };
+pub use angle::{ Angle, AngleUnit };
+pub use time::{ Time, TimeUnit };
+pub use frequency::{ Frequency, FrequencyUnit };
+pub use resolution::{ Resolution, ResolutionUnit };
+pub use percentage::Percentage;
+pub use length_percentage::LengthPercentage;
+pub use color::{ Color, ColorFormat };
+pub use transform::{ Transform, TransformFunction, Matrix, IDENTITY };
+pub use function_list::FunctionList;
+#[doc(hidden)]
+pub use function_list::FunctionListTag;
+pub(crate) use function_list::is_function_list;
+pub use important::Important;
+#[doc(hidden)]
+pub use important::ImportantTag;
+pub(crate) use important::is_important;
+pub use var::Var;
+#[doc(hidden)]
+pub use var::VarTag;
+pub(crate) use var::is_var;
+
+use crate::error::{ Error, offset_in };
+use bevy_reflect::{ Reflect, Struct };
+use std::any::type_name;
+
+// the wrapper types carry a field with a tag type of their own, so they're told apart from other structs
+// by the TypeId of that field instead of by their type name, which isn't stable
+pub(crate) fn has_tag<Tag: Reflect>(struct_reflect: &dyn Struct) -> bool {
+    struct_reflect.field("tag").is_some_and(|tag| tag.is::<Tag>())
+}
+
+// a css value that is written as a single piece of css text
+pub trait CssValue {
+
+    // the css text of the value (i.e. 10px)
+    fn to_css(&self) -> String;
+
+    // sets the value from its css text, surrounding whitespace is ignored
+    fn set_from_css(&mut self, css: &str) -> Result<(), Error>;
+}
+
+// every type in this module, the fields of these types are checked for one after the other
+macro_rules! with_value_types {
+    ($apply:ident) => {
+        $apply!(Length, Angle, Time, Frequency, Resolution, Percentage, LengthPercentage, Color, Transform)
+    };
+}
+
+pub(crate) fn as_css_value(reflect: &dyn Reflect) -> Option<&dyn CssValue> {
+    macro_rules! downcast {
+        ($($value:ty),*) => {
+            $(
+                if let Some(value) = reflect.downcast_ref::<$value>() {
+                    return Some(value);
+                }
+            )*
+        };
+    }
+    with_value_types!(downcast);
+
+    None
+}
+
+pub(crate) fn as_css_value_mut(reflect: &mut dyn Reflect) -> Option<&mut dyn CssValue> {
+    macro_rules! downcast_mut {
+        ($($value:ty),*) => {
+            $(
+                if reflect.is::<$value>() {
+                    return reflect.downcast_mut::<$value>().map(|value| value as &mut dyn CssValue);
+                }
+            )*
+        };
+    }
+    with_value_types!(downcast_mut);
+
+    None
+}
+
+// a fresh value of the type with the given name
+pub(crate) fn blank_value(value_type_name: &str) -> Option<Box<dyn Reflect>> {
+    macro_rules! blank_of {
+        ($($value:ty),*) => {
+            $(
+                if value_type_name == type_name::<$value>() {
+                    return Some(Box::<$value>::default());
+                }
+            )*
+        };
+    }
+    with_value_types!(blank_of);
+
+    None
+}
+
+// splits css text like `-1.5e2px` into its number and whatever follows it (-150 and "px"),
+// an `e` only starts an exponent if digits follow it, so `1em` stays 1 and "em"
+pub(crate) fn split_number(css: &str) -> Option<(f64, &str)> {
+    let bytes = css.as_bytes();
+    let digits_from = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() { i += 1 }
+        i
+    };
+
+    let mut end = if matches!(bytes.first(), Some(b'+') | Some(b'-')) { 1 } else { 0 };
+    let integer_end = digits_from(end);
+    let mut has_digits = integer_end > end;
+    end = integer_end;
+    if bytes.get(end) == Some(&b'.') && bytes.get(end + 1).is_some_and(|b| b.is_ascii_digit()) {
+        end = digits_from(end + 1);
+        has_digits = true;
+    }
+    if !has_digits { return None; }
+
+    if matches!(bytes.get(end), Some(b'e') | Some(b'E')) {
+        let sign = if matches!(bytes.get(end + 1), Some(b'+') | Some(b'-')) { 1 } else { 0 };
+        if bytes.get(end + 1 + sign).is_some_and(|b| b.is_ascii_digit()) {
+            end = digits_from(end + 1 + sign);
+        }
+    }
+
+    css[..end].parse::<f64>().ok().map(|number| (number, &css[end..]))
+}
+
+// parses css text like `10px` into its number and unit, a unitless zero gets the given unit,
+// css only allows it for lengths and angles
+pub(crate) fn parse_dimension<U>(css: &str, what: &str, example: &str, from_suffix: impl Fn(&str) -> Option<U>, unitless_zero: Option<U>) -> Result<(f64, U), Error> {
+    let trimmed = css.trim();
+    let offset = offset_in(css, trimmed);
+    let (number, suffix) = split_number(trimmed)
+        .ok_or_else(|| Error::parse(offset, format!("expected {} like `{}`, found `{}`", what, example, trimmed)))?;
+
+    if suffix.is_empty() && number == 0.0 {
+        if let Some(unit) = unitless_zero {
+            return Ok((number, unit));
+        }
+    }
+    match from_suffix(suffix) {
+        Some(unit) => Ok((number, unit)),
+        None if suffix.is_empty() => Err(Error::parse(offset + trimmed.len(), format!("expected a unit after {} in `{}`", what, trimmed))),
+        None => Err(Error::parse(offset_in(css, suffix), format!("unknown unit `{}` for {}", suffix, what))),
+    }
+}
@@ -0,0 +1,89 @@
+use super::CssValue;
+use crate::error::{ Error, offset_in };
+use bevy_reflect::{ Reflect, FromReflect };
+use std::fmt;
+use std::ops::{ Add, Sub, Mul, Div, Neg };
+use std::str::FromStr;
+
+/// A css `<percentage>` like `50%`, holding the number in front of the `%`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Reflect, FromReflect)]
+#[reflect_value(PartialEq, Debug)]
+pub struct Percentage(pub f64);
+
+impl Percentage {
+    // the part of a whole this percentage stands for (i.e. 50% of 200px is 100px)
+    pub fn of<T: Mul<f64, Output = T>>(self, whole: T) -> T {
+        whole * (self.0 / 100.0)
+    }
+}
+
+impl fmt::Display for Percentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl FromStr for Percentage {
+    type Err = Error;
+
+    fn from_str(css: &str) -> Result<Self, Self::Err> {
+        let trimmed = css.trim();
+        let offset = offset_in(css, trimmed);
+        match super::split_number(trimmed) {
+            Some((number, "%")) => Ok(Percentage(number)),
+            Some((0.0, "")) => Ok(Percentage(0.0)),
+            _ => Err(Error::parse(offset, format!("expected a percentage like `50%`, found `{}`", trimmed))),
+        }
+    }
+}
+
+impl CssValue for Percentage {
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn set_from_css(&mut self, css: &str) -> Result<(), Error> {
+        *self = css.parse()?;
+        Ok(())
+    }
+}
+
+impl Add for Percentage {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Percentage(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Percentage {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Percentage(self.0 - rhs.0)
+    }
+}
+
+impl Mul<f64> for Percentage {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Percentage(self.0 * rhs)
+    }
+}
+
+impl Div<f64> for Percentage {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Percentage(self.0 / rhs)
+    }
+}
+
+impl Neg for Percentage {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Percentage(-self.0)
+    }
+}
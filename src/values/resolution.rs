@@ -0,0 +1,11 @@
+use super::dimension::dimension;
+
+dimension! {
+    /// A css `<resolution>` like `2dppx` or `300dpi`.
+    Resolution(ResolutionUnit, default = Dppx, unitless_zero = false, "a resolution", "2dppx") {
+        Dppx => dppx("dppx", Some(1.0)),
+        X => x("x", Some(1.0)),
+        Dpi => dpi("dpi", Some(1.0 / 96.0)),
+        Dpcm => dpcm("dpcm", Some(2.54 / 96.0)),
+    }
+}
@@ -0,0 +1,9 @@
+use super::dimension::dimension;
+
+dimension! {
+    /// A css `<time>` like `200ms` or `1.5s`.
+    Time(TimeUnit, default = Ms, unitless_zero = false, "a time", "200ms") {
+        Ms => ms("ms", Some(1.0)),
+        S => s("s", Some(1000.0)),
+    }
+}
@@ -0,0 +1,349 @@
+use super::{ CssValue, Length, LengthUnit, LengthPercentage, Angle, AngleUnit, split_number };
+use crate::error::{ Error, offset_in };
+use crate::split;
+use bevy_reflect::{ Reflect, FromReflect };
+use std::fmt;
+use std::str::FromStr;
+
+// a 4x4 matrix in rows, transforming column vectors (x, y, z, w)
+pub type Matrix = [[f64; 4]; 4];
+
+pub const IDENTITY: Matrix = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+// one css transform function, written in css the way the browser does (i.e. TranslateX -> translateX(10px))
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransformFunction {
+    Translate(LengthPercentage, LengthPercentage),
+    TranslateX(LengthPercentage),
+    TranslateY(LengthPercentage),
+    TranslateZ(Length),
+    Translate3d(LengthPercentage, LengthPercentage, Length),
+    Rotate(Angle),
+    RotateX(Angle),
+    RotateY(Angle),
+    RotateZ(Angle),
+    Rotate3d(f64, f64, f64, Angle),
+    Scale(f64, f64),
+    ScaleX(f64),
+    ScaleY(f64),
+    ScaleZ(f64),
+    Scale3d(f64, f64, f64),
+    Skew(Angle, Angle),
+    SkewX(Angle),
+    SkewY(Angle),
+    Matrix([f64; 6]),
+    Matrix3d([f64; 16]),
+    Perspective(Length),
+}
+
+impl TransformFunction {
+    // the name of the function in css
+    pub fn name(&self) -> &'static str {
+        match self {
+            TransformFunction::Translate(..) => "translate",
+            TransformFunction::TranslateX(_) => "translateX",
+            TransformFunction::TranslateY(_) => "translateY",
+            TransformFunction::TranslateZ(_) => "translateZ",
+            TransformFunction::Translate3d(..) => "translate3d",
+            TransformFunction::Rotate(_) => "rotate",
+            TransformFunction::RotateX(_) => "rotateX",
+            TransformFunction::RotateY(_) => "rotateY",
+            TransformFunction::RotateZ(_) => "rotateZ",
+            TransformFunction::Rotate3d(..) => "rotate3d",
+            TransformFunction::Scale(..) => "scale",
+            TransformFunction::ScaleX(_) => "scaleX",
+            TransformFunction::ScaleY(_) => "scaleY",
+            TransformFunction::ScaleZ(_) => "scaleZ",
+            TransformFunction::Scale3d(..) => "scale3d",
+            TransformFunction::Skew(..) => "skew",
+            TransformFunction::SkewX(_) => "skewX",
+            TransformFunction::SkewY(_) => "skewY",
+            TransformFunction::Matrix(_) => "matrix",
+            TransformFunction::Matrix3d(_) => "matrix3d",
+            TransformFunction::Perspective(_) => "perspective",
+        }
+    }
+
+    // the matrix of the function, None if it contains lengths only the browser can resolve (i.e. em or %)
+    pub fn to_matrix(&self) -> Option<Matrix> {
+        let mut matrix = IDENTITY;
+        match *self {
+            TransformFunction::Translate(x, y) => return translation(px_of(x)?, px_of(y)?, 0.0),
+            TransformFunction::TranslateX(x) => return translation(px_of(x)?, 0.0, 0.0),
+            TransformFunction::TranslateY(y) => return translation(0.0, px_of(y)?, 0.0),
+            TransformFunction::TranslateZ(z) => return translation(0.0, 0.0, px(z)?),
+            TransformFunction::Translate3d(x, y, z) => return translation(px_of(x)?, px_of(y)?, px(z)?),
+            TransformFunction::Rotate(angle) | TransformFunction::RotateZ(angle) => return Some(rotation(0.0, 0.0, 1.0, angle)),
+            TransformFunction::RotateX(angle) => return Some(rotation(1.0, 0.0, 0.0, angle)),
+            TransformFunction::RotateY(angle) => return Some(rotation(0.0, 1.0, 0.0, angle)),
+            TransformFunction::Rotate3d(x, y, z, angle) => return Some(rotation(x, y, z, angle)),
+            TransformFunction::Scale(x, y) => { matrix[0][0] = x; matrix[1][1] = y; },
+            TransformFunction::ScaleX(x) => matrix[0][0] = x,
+            TransformFunction::ScaleY(y) => matrix[1][1] = y,
+            TransformFunction::ScaleZ(z) => matrix[2][2] = z,
+            TransformFunction::Scale3d(x, y, z) => { matrix[0][0] = x; matrix[1][1] = y; matrix[2][2] = z; },
+            TransformFunction::Skew(x, y) => { matrix[0][1] = radians(x).tan(); matrix[1][0] = radians(y).tan(); },
+            TransformFunction::SkewX(x) => matrix[0][1] = radians(x).tan(),
+            TransformFunction::SkewY(y) => matrix[1][0] = radians(y).tan(),
+            TransformFunction::Matrix([a, b, c, d, e, f]) => {
+                matrix[0] = [a, c, 0.0, e];
+                matrix[1] = [b, d, 0.0, f];
+            },
+            // matrix3d lists the matrix column by column
+            TransformFunction::Matrix3d(values) => {
+                for (i, value) in values.into_iter().enumerate() {
+                    matrix[i % 4][i / 4] = value;
+                }
+            },
+            TransformFunction::Perspective(distance) => matrix[3][2] = -1.0 / px(distance)?,
+        }
+        Some(matrix)
+    }
+
+    fn args(&self) -> Vec<String> {
+        fn all<T: ToString>(values: &[T]) -> Vec<String> {
+            values.iter().map(T::to_string).collect()
+        }
+        match self {
+            TransformFunction::Translate(x, y) => vec![x.to_string(), y.to_string()],
+            TransformFunction::TranslateX(x) | TransformFunction::TranslateY(x) => vec![x.to_string()],
+            TransformFunction::TranslateZ(z) | TransformFunction::Perspective(z) => vec![z.to_string()],
+            TransformFunction::Translate3d(x, y, z) => vec![x.to_string(), y.to_string(), z.to_string()],
+            TransformFunction::Rotate(angle) | TransformFunction::RotateX(angle) | TransformFunction::RotateY(angle)
+            | TransformFunction::RotateZ(angle) | TransformFunction::SkewX(angle) | TransformFunction::SkewY(angle) => vec![angle.to_string()],
+            TransformFunction::Rotate3d(x, y, z, angle) => vec![x.to_string(), y.to_string(), z.to_string(), angle.to_string()],
+            TransformFunction::Scale(x, y) => all(&[x, y]),
+            TransformFunction::ScaleX(s) | TransformFunction::ScaleY(s) | TransformFunction::ScaleZ(s) => vec![s.to_string()],
+            TransformFunction::Scale3d(x, y, z) => all(&[x, y, z]),
+            TransformFunction::Skew(x, y) => all(&[x, y]),
+            TransformFunction::Matrix(values) => all(values),
+            TransformFunction::Matrix3d(values) => all(values),
+        }
+    }
+}
+
+fn px(length: Length) -> Option<f64> {
+    Some(length.to(LengthUnit::Px)?.value)
+}
+
+fn px_of(length: LengthPercentage) -> Option<f64> {
+    match length {
+        LengthPercentage::Length(length) => px(length),
+        LengthPercentage::Percentage(_) => None,
+    }
+}
+
+fn radians(angle: Angle) -> f64 {
+    angle.to(AngleUnit::Rad).map(|angle| angle.value).unwrap_or_default()
+}
+
+fn translation(x: f64, y: f64, z: f64) -> Option<Matrix> {
+    let mut matrix = IDENTITY;
+    matrix[0][3] = x;
+    matrix[1][3] = y;
+    matrix[2][3] = z;
+    Some(matrix)
+}
+
+// the rotation around the axis (x, y, z) as defined for rotate3d() in the css transforms spec
+fn rotation(x: f64, y: f64, z: f64, angle: Angle) -> Matrix {
+    let length = (x * x + y * y + z * z).sqrt();
+    if length == 0.0 {
+        return IDENTITY;
+    }
+    let (x, y, z) = (x / length, y / length, z / length);
+    let half = radians(angle) / 2.0;
+    let (sc, sq) = (half.sin() * half.cos(), half.sin() * half.sin());
+    [
+        [1.0 - 2.0 * (y * y + z * z) * sq, 2.0 * (x * y * sq - z * sc), 2.0 * (x * z * sq + y * sc), 0.0],
+        [2.0 * (x * y * sq + z * sc), 1.0 - 2.0 * (x * x + z * z) * sq, 2.0 * (y * z * sq - x * sc), 0.0],
+        [2.0 * (x * z * sq - y * sc), 2.0 * (y * z * sq + x * sc), 1.0 - 2.0 * (x * x + y * y) * sq, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]
+}
+
+fn multiply(left: &Matrix, right: &Matrix) -> Matrix {
+    let mut product = [[0.0; 4]; 4];
+    for (row, product_row) in product.iter_mut().enumerate() {
+        for (column, cell) in product_row.iter_mut().enumerate() {
+            *cell = (0..4).map(|i| left[row][i] * right[i][column]).sum();
+        }
+    }
+    product
+}
+
+impl fmt::Display for TransformFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", self.name(), self.args().join(", "))
+    }
+}
+
+// the comma separated arguments of a function, parse errors point into the css they were sliced from
+struct Args<'a> {
+    css: &'a str,
+    name: &'a str,
+    values: Vec<&'a str>,
+}
+
+impl<'a> Args<'a> {
+    fn expect(&self, min: usize, max: usize) -> Result<(), Error> {
+        if (min..=max).contains(&self.values.len()) {
+            return Ok(());
+        }
+        let expected = if min == max { min.to_string() } else { format!("{} or {}", min, max) };
+        let message = format!("expected {} arguments to `{}()`, found {}", expected, self.name, self.values.len());
+        Err(Error::parse(offset_in(self.css, self.name), message))
+    }
+
+    fn get<T: FromStr<Err = Error>>(&self, i: usize) -> Result<T, Error> {
+        self.values[i].parse().map_err(|e: Error| e.offset_by(offset_in(self.css, self.values[i])))
+    }
+
+    fn number(&self, i: usize) -> Result<f64, Error> {
+        match split_number(self.values[i]) {
+            Some((number, "")) => Ok(number),
+            _ => Err(self.error(i, "a number")),
+        }
+    }
+
+    // scales can be written as numbers or percentages (150% is 1.5)
+    fn scale(&self, i: usize) -> Result<f64, Error> {
+        match split_number(self.values[i]) {
+            Some((number, "")) => Ok(number),
+            Some((number, "%")) => Ok(number / 100.0),
+            _ => Err(self.error(i, "a number or percentage")),
+        }
+    }
+
+    fn numbers<const N: usize>(&self) -> Result<[f64; N], Error> {
+        self.expect(N, N)?;
+        let mut numbers = [0.0; N];
+        for (i, number) in numbers.iter_mut().enumerate() {
+            *number = self.number(i)?;
+        }
+        Ok(numbers)
+    }
+
+    fn error(&self, i: usize, expected: &str) -> Error {
+        let message = format!("expected {} in `{}()`, found `{}`", expected, self.name, self.values[i]);
+        Error::parse(offset_in(self.css, self.values[i]), message)
+    }
+}
+
+impl FromStr for TransformFunction {
+    type Err = Error;
+
+    fn from_str(css: &str) -> Result<Self, Self::Err> {
+        let function = split::function(css).ok_or_else(|| {
+            let trimmed = split::trim(css);
+            Error::parse(offset_in(css, trimmed), format!("expected a transform function like `rotate(45deg)`, found `{}`", trimmed))
+        })?;
+        let args = Args { css, name: function.name, values: split::split_commas(function.args) };
+
+        let function = match args.name.to_ascii_lowercase().as_str() {
+            "translate" => {
+                args.expect(1, 2)?;
+                let y = if args.values.len() == 2 { args.get(1)? } else { LengthPercentage::default() };
+                TransformFunction::Translate(args.get(0)?, y)
+            },
+            "translatex" => { args.expect(1, 1)?; TransformFunction::TranslateX(args.get(0)?) },
+            "translatey" => { args.expect(1, 1)?; TransformFunction::TranslateY(args.get(0)?) },
+            "translatez" => { args.expect(1, 1)?; TransformFunction::TranslateZ(args.get(0)?) },
+            "translate3d" => { args.expect(3, 3)?; TransformFunction::Translate3d(args.get(0)?, args.get(1)?, args.get(2)?) },
+            "rotate" => { args.expect(1, 1)?; TransformFunction::Rotate(args.get(0)?) },
+            "rotatex" => { args.expect(1, 1)?; TransformFunction::RotateX(args.get(0)?) },
+            "rotatey" => { args.expect(1, 1)?; TransformFunction::RotateY(args.get(0)?) },
+            "rotatez" => { args.expect(1, 1)?; TransformFunction::RotateZ(args.get(0)?) },
+            "rotate3d" => {
+                args.expect(4, 4)?;
+                TransformFunction::Rotate3d(args.number(0)?, args.number(1)?, args.number(2)?, args.get(3)?)
+            },
+            "scale" => {
+                args.expect(1, 2)?;
+                let x = args.scale(0)?;
+                let y = if args.values.len() == 2 { args.scale(1)? } else { x };
+                TransformFunction::Scale(x, y)
+            },
+            "scalex" => { args.expect(1, 1)?; TransformFunction::ScaleX(args.scale(0)?) },
+            "scaley" => { args.expect(1, 1)?; TransformFunction::ScaleY(args.scale(0)?) },
+            "scalez" => { args.expect(1, 1)?; TransformFunction::ScaleZ(args.scale(0)?) },
+            "scale3d" => { args.expect(3, 3)?; TransformFunction::Scale3d(args.scale(0)?, args.scale(1)?, args.scale(2)?) },
+            "skew" => {
+                args.expect(1, 2)?;
+                let y = if args.values.len() == 2 { args.get(1)? } else { Angle::default() };
+                TransformFunction::Skew(args.get(0)?, y)
+            },
+            "skewx" => { args.expect(1, 1)?; TransformFunction::SkewX(args.get(0)?) },
+            "skewy" => { args.expect(1, 1)?; TransformFunction::SkewY(args.get(0)?) },
+            "matrix" => TransformFunction::Matrix(args.numbers()?),
+            "matrix3d" => TransformFunction::Matrix3d(args.numbers()?),
+            "perspective" => { args.expect(1, 1)?; TransformFunction::Perspective(args.get(0)?) },
+            _ => return Err(Error::parse(offset_in(css, args.name), format!("unknown transform function `{}()`", args.name))),
+        };
+        Ok(function)
+    }
+}
+
+/// The value of the css `transform` property, a list of transform functions that are applied in order.
+/// An empty list is written as `none`.
+#[derive(Debug, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect_value(PartialEq, Debug)]
+pub struct Transform(pub Vec<TransformFunction>);
+
+impl Transform {
+    pub fn none() -> Self {
+        Transform(Vec::new())
+    }
+
+    // the transform with another function applied after the ones it has already
+    pub fn then(mut self, function: TransformFunction) -> Self {
+        self.0.push(function);
+        self
+    }
+
+    // the matrix of all functions combined, None if any of them contains lengths only the browser can resolve
+    pub fn to_matrix(&self) -> Option<Matrix> {
+        self.0.iter().try_fold(IDENTITY, |matrix, function| Some(multiply(&matrix, &function.to_matrix()?)))
+    }
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("none");
+        }
+        let functions = self.0.iter().map(TransformFunction::to_string).collect::<Vec<String>>();
+        f.write_str(&functions.join(" "))
+    }
+}
+
+impl FromStr for Transform {
+    type Err = Error;
+
+    fn from_str(css: &str) -> Result<Self, Self::Err> {
+        if css.trim().eq_ignore_ascii_case("none") {
+            return Ok(Transform::none());
+        }
+
+        let functions = split::functions(css)?.into_iter()
+            .map(|function| function.text.parse().map_err(|e: Error| e.offset_by(offset_in(css, function.text))))
+            .collect::<Result<Vec<TransformFunction>, Error>>()?;
+        Ok(Transform(functions))
+    }
+}
+
+impl CssValue for Transform {
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn set_from_css(&mut self, css: &str) -> Result<(), Error> {
+        *self = css.parse()?;
+        Ok(())
+    }
+}
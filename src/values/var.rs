@@ -0,0 +1,37 @@
+use bevy_reflect::{ Reflect, FromReflect, Struct };
+
+/// A reference to a custom property, like `var(--brand-color, #002eff)`.
+/// The fallback is used by the browser while the custom property isn't set.
+#[derive(Reflect, FromReflect, Clone, PartialEq, Debug)]
+pub struct Var<T: Reflect + FromReflect> {
+    // the name of the custom property without the leading dashes (brand-color)
+    pub name: String,
+    pub fallback: Option<T>,
+    tag: VarTag,
+}
+
+// tells the reflection code that a struct is a Var
+#[doc(hidden)]
+#[derive(Reflect, FromReflect, Clone, Copy, PartialEq, Debug, Default)]
+pub struct VarTag;
+
+impl<T: Reflect + FromReflect> Var<T> {
+    // the name can be given with or without the leading dashes
+    pub fn new(name: &str) -> Self {
+        Self { name: name.trim_start_matches("--").to_owned(), fallback: None, tag: VarTag }
+    }
+
+    pub fn with_fallback(name: &str, fallback: T) -> Self {
+        Self { fallback: Some(fallback), ..Self::new(name) }
+    }
+}
+
+impl<T: Reflect + FromReflect> Default for Var<T> {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+pub(crate) fn is_var(struct_reflect: &dyn Struct) -> bool {
+    super::has_tag::<VarTag>(struct_reflect)
+}
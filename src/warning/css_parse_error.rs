@@ -5,5 +5,4 @@ pub fn throw(css_string: &str) {
     
     let warning = format!("{} {}\n{}\n{}", warning_message, css_string, suggestion_1, suggestion_2);
     log::warn!("{}", warning);
-    println!("{}", warning);
 }
\ No newline at end of file
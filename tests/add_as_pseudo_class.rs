@@ -61,16 +61,13 @@ fn get_style_content<S: Into<String>>(id:  S) -> String {
    let style_id = format!("#{}", id.into());
    let style = document.query_selector(&style_id).unwrap();
    info!("{:?}", style);
-   let style_content = style.unwrap().text_content().unwrap(); 
-
-   style_content
+   style.unwrap().text_content().unwrap()
 }
 
 fn get_document() -> Document {
     // grab the current document
    let window = web_sys::window().expect("No global `window` found");
-   let document = window.document().expect("couldn't get `document");
-   document
+   window.document().expect("couldn't get `document")
 }
 
 #[wasm_bindgen_test]
@@ -81,7 +78,7 @@ fn test_add_as_pseudo_class (){
     b.add_as_pseudo_class(&get_document());
 
 
-    assert_eq!(get_style_content("rusty-css-B_hover"), ".B:hover { prop: 200px; nested:  func1(func_prop1) func2(func_prop2); }");
+    assert_eq!(get_style_content("rusty-css-add_as_pseudo_class-B_hover"), ".add_as_pseudo_class-B:hover { prop: 200px; nested:  func1(func_prop1) func2(func_prop2); }");
 }
 
 #[wasm_bindgen_test]
@@ -94,5 +91,5 @@ fn test_add_as_pseudo_class_2 (){
     let b = B_hover::create();
     b.add_as_pseudo_class(&get_document());
 
-    assert_eq!(get_style_content("rusty-css-B_hover"), ".B:hover { prop: 200px; nested:  func1(func_prop1) func2(func_prop2); }");
+    assert_eq!(get_style_content("rusty-css-add_as_pseudo_class-B_hover"), ".add_as_pseudo_class-B:hover { prop: 200px; nested:  func1(func_prop1) func2(func_prop2); }");
 }
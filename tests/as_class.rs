@@ -15,7 +15,6 @@ struct NStruct {
 
 #[derive(Reflect)]
 struct BB {
-    append: String,
     prop: String,
     nested: NStruct,
 }
@@ -23,7 +22,6 @@ struct BB {
 impl Style for BB {
     fn create() -> Self {
         Self {
-            append: ":arbitrary_name".to_string(),
             prop: "200px".to_string(),
             nested: NStruct { 
                 func1: "func_prop1".to_string(), 
@@ -31,6 +29,10 @@ impl Style for BB {
             }
         }
     }
+
+    fn selector(&self) -> Selector {
+        Selector::new().pseudo_class("arbitrary_name")
+    }
 }
 
 #[wasm_bindgen_test]
@@ -43,7 +45,7 @@ fn test_class_export_with_pseudo_classes_in_style_tag_1 (){
     let b = BB::create();
     let class_name = &b.as_class(&document).unwrap();
 
-    assert_eq!(class_name, "BB");
+    assert_eq!(class_name, "as_class-BB");
 }
 
 #[wasm_bindgen_test]
@@ -57,10 +59,10 @@ fn test_class_export_with_pseudo_classes_in_style_tag_2 (){
     let class_name = &b.as_class(&document).unwrap();
 
     // grab the contents of the style tag of the document again
-    let style = document.query_selector("#rusty-css-BB").unwrap();
+    let style = document.query_selector("#rusty-css-as_class-BB").unwrap();
     let style_content = style.unwrap().text_content().unwrap();
 
     // compare the inserted style with the computed class string
-    assert_eq!(style_content, b.as_class_string(&class_name).unwrap());
+    assert_eq!(style_content, b.as_class_string(class_name).unwrap());
 
 }
\ No newline at end of file
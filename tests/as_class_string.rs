@@ -30,14 +30,13 @@ impl Style for B {
 #[test]
 fn test_standard_class_string_export (){
     let b = B::create();
-    assert_eq!(b.as_class_string( &b.get_struct_name().unwrap() ).unwrap(), ".B { prop: 200px; nested:  func1(func_prop1) func2(func_prop2); }");
+    assert_eq!(b.as_class_string( &b.get_struct_name().unwrap() ).unwrap(), ".as_class_string-B { prop: 200px; nested:  func1(func_prop1) func2(func_prop2); }");
 }
 
 // test class string with pseudo classes 1
 
 #[derive(Reflect)]
 struct BA {
-    append: String,
     prop: String,
     nested: NStruct,
 }
@@ -45,7 +44,6 @@ struct BA {
 impl Style for BA {
     fn create() -> Self {
         Self {
-            append: ":before".to_string(),
             prop: "200px".to_string(),
             nested: NStruct { 
                 func1: "func_prop1".to_string(), 
@@ -53,12 +51,16 @@ impl Style for BA {
             }
         }
     }
+
+    fn selector(&self) -> Selector {
+        Selector::new().pseudo_element("before")
+    }
 }
 
 #[test]
 fn test_class_string_export_with_pseudo_classes_1 (){
     let b = BA::create();
-    assert_eq!(b.as_class_string( &b.get_struct_name().unwrap() ).unwrap(), ".BA:before { prop: 200px; nested:  func1(func_prop1) func2(func_prop2); }");
+    assert_eq!(b.as_class_string( &b.get_struct_name().unwrap() ).unwrap(), ".as_class_string-BA::before { prop: 200px; nested:  func1(func_prop1) func2(func_prop2); }");
 }
 
 
@@ -66,7 +68,6 @@ fn test_class_string_export_with_pseudo_classes_1 (){
 
 #[derive(Reflect)]
 struct BB {
-    append: String,
     prop: String,
     nested: NStruct,
 }
@@ -74,7 +75,6 @@ struct BB {
 impl Style for BB {
     fn create() -> Self {
         Self {
-            append: ":arbitrary_name".to_string(),
             prop: "200px".to_string(),
             nested: NStruct { 
                 func1: "func_prop1".to_string(), 
@@ -82,10 +82,14 @@ impl Style for BB {
             }
         }
     }
+
+    fn selector(&self) -> Selector {
+        Selector::new().pseudo_class("arbitrary_name")
+    }
 }
 
 #[test]
 fn test_class_string_export_with_pseudo_classes_2 (){
     let b = BB::create();
-    assert_eq!(b.as_class_string( &b.get_struct_name().unwrap() ).unwrap(), ".BB:arbitrary_name { prop: 200px; nested:  func1(func_prop1) func2(func_prop2); }");
+    assert_eq!(b.as_class_string( &b.get_struct_name().unwrap() ).unwrap(), ".as_class_string-BB:arbitrary_name { prop: 200px; nested:  func1(func_prop1) func2(func_prop2); }");
 }
\ No newline at end of file
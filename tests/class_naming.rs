@@ -0,0 +1,86 @@
+use rusty_css::*;
+use bevy_reflect::Reflect;
+
+mod header {
+    use rusty_css::*;
+    use bevy_reflect::Reflect;
+
+    // the module path is in front of the name by default
+    #[derive(Reflect, Style)]
+    pub struct Button {
+        #[css(default = "blue")]
+        pub color: String,
+    }
+
+    #[derive(Reflect, Style)]
+    #[allow(non_camel_case_types)]
+    pub struct Button_hover {
+        #[css(default = "navy")]
+        pub color: String,
+    }
+}
+
+mod footer {
+    use rusty_css::*;
+    use bevy_reflect::Reflect;
+
+    #[derive(Reflect, Style)]
+    #[css(naming = "hash")]
+    pub struct Button {
+        #[css(default = "gray")]
+        pub color: String,
+    }
+}
+
+#[derive(Reflect, Style)]
+#[css(prefix = "app")]
+struct Button {
+    #[css(default = "red")]
+    color: String,
+}
+
+#[derive(Reflect, Style)]
+#[css(naming = "type")]
+struct Link {
+    #[css(default = "underline")]
+    text_decoration: String,
+}
+
+#[test]
+fn test_class_naming_strategies() {
+    assert_eq!(header::Button::create().get_struct_name().unwrap(), "class_naming-header-Button");
+    assert_eq!(Button::create().get_struct_name().unwrap(), "app-Button");
+    assert_eq!(Link::create().get_struct_name().unwrap(), "Link");
+
+    // the path comes from module_path!() instead of std::any::type_name
+    assert_eq!(footer::Button::create().type_path(), "class_naming::footer::Button");
+
+    let footer_class = footer::Button::create().get_struct_name().unwrap();
+    assert!(footer_class.starts_with("Button-"));
+    assert_eq!(footer_class.len(), "Button-".len() + 8);
+    assert_eq!(footer_class, ClassNaming::Hash.class_name("class_naming::footer::Button"));
+
+    // the styles of the same name don't share a class anymore
+    let mut registry = MemoryRegistry::new();
+    header::Button::create().as_class(&mut registry).unwrap();
+    footer::Button::create().as_class(&mut registry).unwrap();
+    Button::create().as_class(&mut registry).unwrap();
+    header::Button_hover::create().add_as_pseudo_class(&mut registry);
+    assert_eq!(registry.len(), 4);
+    assert_eq!(
+        registry.get("class_naming-header-Button:hover"),
+        Some(".class_naming-header-Button:hover { color: navy; }")
+    );
+}
+
+#[test]
+fn test_class_names_are_css_identifiers() {
+    assert_eq!(ClassNaming::TypeName.class_name("my_app::Wrapper<my_app::Foo, u8>"), "Wrapper-Foo-u8");
+    assert_eq!(ClassNaming::ModulePath.class_name("my_app::Wrapper<my_app::Foo>"), "my_app-Wrapper-my_app-Foo");
+    assert_eq!(ClassNaming::Prefix("1st".to_string()).class_name("my_app::Card"), "_1st-Card");
+    assert_eq!(ClassNaming::TypeName.class_name("[u8; 4]"), "u8-4");
+
+    // the hash only depends on the path of the type, so it's the same on the server and in the browser
+    assert_eq!(ClassNaming::Hash.class_name("my_app::Card"), "Card-05ac7551");
+    assert_ne!(ClassNaming::Hash.class_name("my_app::Card"), ClassNaming::Hash.class_name("other::Card"));
+}
@@ -0,0 +1,74 @@
+use rusty_css::*;
+use rusty_css::values::*;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    #[css(default = "#ff8800")]
+    color: Color,
+    #[css(default = Color::rgb(0, 0, 255).with_format(ColorFormat::Rgb))]
+    background_color: Color,
+    #[css(default = Color::hsl(120.0, 1.0, 0.25).with_format(ColorFormat::Hsl))]
+    border_color: Color,
+    outline_color: Option<Color>,
+}
+
+#[test]
+fn test_inline_color() {
+    let mut a = A::create();
+    a.outline_color = Some(Color::rgba(255, 255, 255, 0.5).with_format(ColorFormat::Hwb));
+    assert_eq!(
+        a.inline(),
+        "color: #ff8800; background-color: rgb(0, 0, 255); border-color: hsl(120, 100%, 25.1%); outline-color: hwb(0 100% 0% / 0.5); "
+    );
+}
+
+#[test]
+fn test_from_string_color_keeps_format() {
+    let mut a = A::create();
+    a.try_set_from_inline_string("color: rgb(255 0 0 / 50%); background-color: #0F0; border-color: rebeccapurple; outline-color: transparent;").unwrap();
+    assert_eq!(a.inline(), "color: #ff000080; background-color: rgb(0, 255, 0); border-color: hsl(270, 50%, 40%); outline-color: #00000000; ");
+}
+
+#[test]
+fn test_parse_color() {
+    assert_eq!("#abc".parse::<Color>(), Ok(Color::rgb(0xaa, 0xbb, 0xcc)));
+    assert_eq!("#11223344".parse::<Color>(), Ok(Color::rgba(0x11, 0x22, 0x33, 0x44 as f64 / 255.0)));
+    assert_eq!("rgba(10, 20, 30, 0.25)".parse::<Color>(), Ok(Color::rgba(10, 20, 30, 0.25)));
+    assert_eq!("rgb(100%, 0%, 50%)".parse::<Color>(), Ok(Color::rgb(255, 0, 128)));
+    assert_eq!("hsla(0.5turn, 100%, 50%, 1)".parse::<Color>(), Ok(Color::rgb(0, 255, 255)));
+    assert_eq!("hwb(0 0% 0%)".parse::<Color>(), Ok(Color::rgb(255, 0, 0)));
+    assert_eq!("DarkSlateGray".parse::<Color>(), Ok(Color::rgb(0x2f, 0x4f, 0x4f)));
+
+    // comments are whitespace, even around the slash
+    assert_eq!("rgb(255 /* r */ 0 0)".parse::<Color>(), Ok(Color::rgb(255, 0, 0)));
+    assert_eq!("rgb(0 0 0 / /*a*/ .5)".parse::<Color>(), Ok(Color::rgba(0, 0, 0, 0.5)));
+    assert_eq!("rgb(0, /* , */ 0, 0)".parse::<Color>(), Ok(Color::rgb(0, 0, 0)));
+}
+
+#[test]
+fn test_parse_invalid_color() {
+    let mut a = A::create();
+    assert_eq!(
+        a.try_set_from_inline_string("color: rgb(1, 2);"),
+        Err(Error::Parse { offset: 11, message: "expected 3 values and an optional alpha in `rgb()`, found `1, 2`".to_string() })
+    );
+    assert_eq!(
+        a.try_set_from_inline_string("color: hsl(red, 10%, 10%);"),
+        Err(Error::Parse { offset: 11, message: "expected a hue like `120` or `120deg` in `hsl()`, found `red`".to_string() })
+    );
+    assert!("#12345".parse::<Color>().is_err());
+    assert!("bluish".parse::<Color>().is_err());
+}
+
+#[test]
+fn test_color_helpers() {
+    let black = Color::rgb(0, 0, 0);
+    let white = Color::rgb(255, 255, 255);
+    assert_eq!(black.mix(white, 0.5), Color::rgb(128, 128, 128));
+    assert_eq!(Color::hsl(0.0, 1.0, 0.5).lighten(0.25), Color::rgb(255, 128, 128));
+    assert_eq!(Color::hsl(0.0, 1.0, 0.5).darken(0.25), Color::rgb(128, 0, 0));
+    assert_eq!(black.contrast(white), 21.0);
+    assert_eq!(white.contrast(white), 1.0);
+    assert!(Color::rgb(0x76, 0x76, 0x76).contrast(white) > 4.5);
+}
@@ -0,0 +1,63 @@
+use rusty_css::*;
+use rusty_css::media::Orientation;
+use rusty_css::values::Length;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Style)]
+struct Grid {
+    #[css(default = "block")]
+    display: String,
+    #[css(supports = SupportsCondition::declaration("display", "grid"))]
+    grid: GridDisplay,
+    #[css(container = ContainerQuery::named("sidebar").min_width(Length::px(400.0)), supports = "(display: grid)")]
+    wide: GridDisplay,
+    #[css(nest = "& > *", media = "print", container = "(orientation: portrait)")]
+    items: GridDisplay,
+}
+
+#[derive(Reflect, Style)]
+struct GridDisplay {
+    #[css(default = "grid")]
+    display: String,
+}
+
+#[derive(Reflect, Style)]
+#[css(supports = !SupportsCondition::selector(":has(a)"), container = "card (width > 20em)")]
+struct NoHas {
+    #[css(default = "red")]
+    color: String,
+}
+
+#[test]
+fn test_condition_builders() {
+    let query = ContainerQuery::named("sidebar").min_width(Length::px(400.0)).orientation(Orientation::Portrait);
+    assert_eq!(query.to_string(), "sidebar (min-width: 400px) and (orientation: portrait)");
+    assert_eq!(ContainerQuery::new().max_inline_size(Length::rem(30.0)).wrap(".A { }"), "@container (max-inline-size: 30rem) { .A { } }");
+
+    let condition = SupportsCondition::declaration("display", "grid")
+        .and(SupportsCondition::declaration("gap", "1px").or(SupportsCondition::selector("a:has(b)")))
+        .and(!SupportsCondition::raw("font-tech(color-COLRv1)"));
+    assert_eq!(condition.to_string(), "(display: grid) and ((gap: 1px) or selector(a:has(b))) and (not (font-tech(color-COLRv1)))");
+
+    // raw conditions are put in parentheses when they're combined
+    let condition = SupportsCondition::raw("display: grid").and(SupportsCondition::raw("(gap: 1px) or (grid-gap: 1px)"));
+    assert_eq!(condition.to_string(), "(display: grid) and ((gap: 1px) or (grid-gap: 1px))");
+
+    // a query on any container doesn't need an @container block
+    assert_eq!(ContainerQuery::new().wrap(".A { }"), ".A { }");
+}
+
+#[test]
+fn test_class_string_with_container_and_supports() {
+    assert_eq!(
+        Grid::create().as_class_string("Grid").unwrap(),
+        ".Grid { display: block; } \
+        @supports (display: grid) { .Grid { display: grid; } } \
+        @container sidebar (min-width: 400px) { @supports (display: grid) { .Grid { display: grid; } } } \
+        @media print { @container (orientation: portrait) { .Grid > * { display: grid; } } }"
+    );
+    assert_eq!(
+        NoHas::create().as_class_string("NoHas").unwrap(),
+        "@container card (width > 20em) { @supports not selector(:has(a)) { .NoHas { color: red; } } }"
+    );
+}
@@ -0,0 +1,59 @@
+use rusty_css::*;
+use rusty_css::values::*;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct Theme {
+    #[css(custom, default = "#002eff")]
+    brand_color: Color,
+    #[css(custom, unit = "px")]
+    gap: f64,
+    color: Var<Color>,
+    #[css(default = Var::with_fallback("gap", Length::px(8.0)))]
+    margin: Var<Length>,
+    font_family: Option<Var<Vec<String>>>,
+}
+
+#[test]
+fn test_inline_custom_properties() {
+    let mut theme = Theme::create();
+    theme.color = Var::new("--brand-color");
+    assert_eq!(theme.inline(), "--brand-color: #002eff; --gap: 0px; color: var(--brand-color); margin: var(--gap, 8px); ");
+
+    theme.font_family = Some(Var::with_fallback("font", vec!["Foo".to_string(), "serif".to_string()]));
+    assert!(theme.inline().ends_with("font-family: var(--font, Foo, serif); "));
+}
+
+#[test]
+fn test_from_string_custom_properties() {
+    let mut theme = Theme::create();
+    // fallbacks that aren't Strings, bools, numbers or rusty_css::values types are read into an existing fallback
+    theme.font_family = Some(Var::with_fallback("font", Vec::new()));
+    let css = "--brand-color: red; --gap: 4px; color: var(--brand-color, rgb(0, 0, 255)); margin: var( --gap ); font-family: var(--font, \"Bar Baz\", serif);";
+    theme.try_set_from_inline_string(css).unwrap();
+    assert_eq!(theme.brand_color, Color::rgb(255, 0, 0));
+    assert_eq!(theme.gap, 4.0);
+    assert_eq!(theme.color, Var::with_fallback("brand-color", Color::rgb(0, 0, 255)));
+    assert_eq!(theme.margin, Var::new("gap"));
+    assert_eq!(theme.font_family, Some(Var::with_fallback("font", vec!["\"Bar Baz\"".to_string(), "serif".to_string()])));
+
+    // custom properties are only read from their dashed name
+    assert!(matches!(theme.try_set_from_inline_string("brand-color: blue;"), Err(Error::UnknownProperty(_))));
+}
+
+#[test]
+fn test_from_string_var_errors() {
+    let mut theme = Theme::create();
+    assert!(matches!(
+        theme.try_set_from_inline_string("color: #fff;"),
+        Err(Error::Parse { offset: 7, .. })
+    ));
+    assert!(matches!(
+        theme.try_set_from_inline_string("color: var(brand);"),
+        Err(Error::Parse { offset: 11, .. })
+    ));
+    assert!(matches!(
+        theme.try_set_from_inline_string("color: var(--brand, #ggg);"),
+        Err(Error::Parse { offset: 20, .. })
+    ));
+}
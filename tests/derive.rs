@@ -0,0 +1,86 @@
+use rusty_css::*;
+use bevy_reflect::{ Reflect };
+
+// plain strings with and without defaults
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    #[css(default = "4em")]
+    width: String,
+    #[css(default = "rgb(69,13,37)")]
+    background: String,
+    height: String,
+}
+
+#[test]
+fn test_derive_string_defaults() {
+    let a = A::create();
+    assert_eq!(a, A { width: "4em".to_string(), background: "rgb(69,13,37)".to_string(), height: "".to_string() });
+    assert_eq!(a.inline(), "width: 4em; background: rgb(69,13,37); height: ; ");
+}
+
+// nested struct read from the css default
+
+#[allow(non_snake_case)]
+#[derive(Reflect, Default, PartialEq, Debug)]
+struct NestedTransformStruct {
+    skewX: String,
+    skewY: String,
+}
+
+#[derive(Reflect, Style)]
+struct B {
+    #[css(default = "2rem")]
+    height: String,
+    #[css(default = "skewX(20deg) skewY(30deg)")]
+    transform: NestedTransformStruct,
+}
+
+#[test]
+fn test_derive_nested_default() {
+    let b = B::create();
+    assert_eq!(b.transform, NestedTransformStruct { skewX: "20deg".to_string(), skewY: "30deg".to_string() });
+    assert_eq!(b.inline(), "height: 2rem; transform:  skewX(20deg) skewY(30deg); ");
+}
+
+// rust expressions as defaults
+
+#[derive(Reflect, Style)]
+struct C {
+    #[css(default = vec!["a".to_string(), "b".to_string()])]
+    list: Vec<String>,
+    #[css(default = String::from("underscore_value"))]
+    prop_with_underscore: String,
+}
+
+#[test]
+fn test_derive_expression_default() {
+    let c = C::create();
+    assert_eq!(c.inline(), "list: a, b; prop-with-underscore: underscore_value; ");
+}
+
+// css defaults and selectors that can't be read panic with the name of the field or struct
+
+#[derive(Reflect, Style)]
+struct D {
+    #[css(default = "wide", unit = "px")]
+    width: f64,
+}
+
+#[derive(Reflect, Style)]
+#[css(selector = "::")]
+struct E {
+    width: String,
+}
+
+#[test]
+#[should_panic(expected = "invalid default for field `width`")]
+fn test_derive_invalid_css_default() {
+    D::create();
+}
+
+#[test]
+#[should_panic(expected = "invalid selector `::` of `E`")]
+fn test_derive_invalid_selector() {
+    E::create().selector();
+}
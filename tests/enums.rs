@@ -0,0 +1,114 @@
+use rusty_css::*;
+use bevy_reflect::{ Reflect, FromReflect };
+
+// unit variants as keywords
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+enum Display {
+    #[default]
+    Block,
+    InlineFlex,
+    None,
+}
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+enum BackgroundRepeat {
+    #[default]
+    Repeat,
+    RepeatX,
+    NoRepeat,
+}
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    #[css(default = "inline-flex")]
+    display: Display,
+    background_repeat: Vec<BackgroundRepeat>,
+}
+
+#[test]
+fn test_inline_keywords() {
+    let mut a = A::create();
+    a.background_repeat = vec![BackgroundRepeat::RepeatX, BackgroundRepeat::NoRepeat];
+    assert_eq!(a.inline(), "display: inline-flex; background-repeat: repeat-x, no-repeat; ");
+}
+
+#[test]
+fn test_from_string_keywords() {
+    let mut a = A::create();
+    a.background_repeat = vec![BackgroundRepeat::Repeat, BackgroundRepeat::Repeat];
+    a.try_set_from_inline_string("display: none; background-repeat: no-repeat, repeat-x").unwrap();
+    assert_eq!(a, A { display: Display::None, background_repeat: vec![BackgroundRepeat::NoRepeat, BackgroundRepeat::RepeatX] });
+}
+
+#[test]
+fn test_from_string_unknown_keyword() {
+    let mut a = A::create();
+    assert_eq!(
+        a.try_set_from_inline_string("display: grid"),
+        Err(Error::Parse { offset: 9, message: "expected one of `block`, `inline-flex`, `none`, found `grid`".to_string() })
+    );
+    assert_eq!(a.display, Display::InlineFlex);
+}
+
+// tuple and struct variants as functions
+
+#[derive(Reflect, FromReflect, PartialEq, Debug)]
+enum Rotation {
+    Rotate(f32),
+    RotateX(f32),
+    Rotate3d(f32, f32, f32, f32),
+}
+
+#[derive(Reflect, FromReflect, PartialEq, Debug)]
+enum Shadow {
+    DropShadow { offset_x: String, offset_y: String, color: String },
+    None,
+}
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct B {
+    #[css(unit = "deg", default = Rotation::RotateX(20.0))]
+    transform: Rotation,
+    #[css(default = Shadow::None)]
+    filter: Shadow,
+}
+
+#[test]
+fn test_inline_functions() {
+    let mut b = B::create();
+    assert_eq!(b.inline(), "transform: rotateX(20deg); filter: none; ");
+
+    b.filter = Shadow::DropShadow { offset_x: "1px".to_string(), offset_y: "2px".to_string(), color: "red".to_string() };
+    assert_eq!(b.inline(), "transform: rotateX(20deg); filter: drop-shadow(1px 2px red); ");
+
+    b.transform = Rotation::Rotate3d(1.0, 0.0, 0.0, 90.0);
+    assert_eq!(b.inline(), "transform: rotate3d(1deg, 0deg, 0deg, 90deg); filter: drop-shadow(1px 2px red); ");
+}
+
+#[test]
+fn test_from_string_functions() {
+    let mut b = B::create();
+    b.try_set_from_inline_string("transform: rotateX(-5deg); filter: drop-shadow(1px /* x */ 2px rgb(0 0 0))").unwrap();
+    assert_eq!(b.transform, Rotation::RotateX(-5.0));
+    assert_eq!(b.filter, Shadow::DropShadow { offset_x: "1px".to_string(), offset_y: "2px".to_string(), color: "rgb(0 0 0)".to_string() });
+
+    b.try_set_from_inline_string("transform: rotate3d(1, 0, 0, 90deg); filter: none").unwrap();
+    assert_eq!(b.transform, Rotation::Rotate3d(1.0, 0.0, 0.0, 90.0));
+    assert_eq!(b.filter, Shadow::None);
+}
+
+#[test]
+fn test_from_string_wrong_argument_count() {
+    let mut b = B::create();
+    assert_eq!(
+        b.try_set_from_inline_string("transform: rotate(1deg, 2deg)"),
+        Err(Error::Parse { offset: 11, message: "expected 1 arguments to `rotate()`, found 2".to_string() })
+    );
+
+    // drop-shadow() separates its arguments by whitespace
+    assert_eq!(
+        b.try_set_from_inline_string("filter: drop-shadow(1px, 2px, red)"),
+        Err(Error::Parse { offset: 23, message: "`drop-shadow()` separates its arguments by whitespace, found `,`".to_string() })
+    );
+}
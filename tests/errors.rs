@@ -0,0 +1,110 @@
+use rusty_css::*;
+use bevy_reflect::{ Reflect };
+use std::path::PathBuf;
+
+#[derive(Reflect, PartialEq, Debug)]
+struct NStruct {
+    func1: String,
+    func2: String,
+}
+
+#[derive(Reflect, PartialEq, Debug)]
+struct A {
+    width: String,
+    border: (String, String, String),
+    nested: NStruct,
+}
+
+impl Style for A {
+    fn create() -> Self {
+        Self {
+            width: "200px".to_string(),
+            border: ("thick".to_string(), "double".to_string(), "#32a1ce".to_string()),
+            nested: NStruct {
+                func1: "func_prop1".to_string(),
+                func2: "func_prop2".to_string()
+            }
+        }
+    }
+}
+
+#[test]
+fn test_try_set_from_inline_string() {
+    let mut a = A::create();
+    assert_eq!(a.try_set_from_inline_string("width: 10px; nested: func2(val2);"), Ok(()));
+
+    let mut newa = A::create();
+    newa.width = "10px".to_string();
+    newa.nested.func2 = "val2".to_string();
+    assert_eq!(a, newa);
+}
+
+#[test]
+fn test_missing_colon() {
+    let mut a = A::create();
+    assert_eq!(
+        a.try_set_from_inline_string("width: 10px;  height 20px;"),
+        Err(Error::Parse { offset: 14, message: "expected `property: value`, found `height 20px`".to_string() })
+    );
+}
+
+#[test]
+fn test_unknown_property() {
+    let mut a = A::create();
+    assert_eq!(a.try_set_from_inline_string("height: 20px;"), Err(Error::UnknownProperty("height".to_string())));
+}
+
+#[test]
+fn test_unclosed_parenthesis() {
+    let mut a = A::create();
+    assert_eq!(
+        a.try_set_from_inline_string("width: 10px; nested: func1(a) func2(b;"),
+        Err(Error::Parse { offset: 35, message: "unclosed parenthesis of `func2(`".to_string() })
+    );
+}
+
+#[test]
+fn test_missing_function() {
+    let mut a = A::create();
+    let error = a.try_set_from_inline_string("nested: func1(a) b;").unwrap_err();
+    assert!(matches!(error, Error::Parse { offset: 17, .. }));
+}
+
+#[test]
+fn test_too_few_tuple_values() {
+    let mut a = A::create();
+    let error = a.try_set_from_inline_string("border: thin solid;").unwrap_err();
+    assert!(matches!(error, Error::Parse { offset: 18, .. }));
+}
+
+#[test]
+fn test_lenient_set_from_inline_string() {
+    // malformed declarations are skipped instead of panicking,
+    // an unclosed parenthesis swallows the rest of the css like it does in the browser
+    let mut a = A::create();
+    a.set_from_inline_string("width 10px; height: 20px; border: thin solid; width: 30px; nested: func1(a; width: 40px;".to_string());
+
+    let mut newa = A::create();
+    newa.width = "30px".to_string();
+    assert_eq!(a, newa);
+}
+
+// fields of unsupported types
+
+#[derive(Reflect)]
+struct B {
+    src: PathBuf,
+}
+
+impl Style for B {
+    fn create() -> Self {
+        Self { src: PathBuf::new() }
+    }
+}
+
+#[test]
+fn test_type_mismatch() {
+    let mut b = B::create();
+    let error = b.try_set_from_inline_string("src: image.png").unwrap_err();
+    assert!(matches!(error, Error::TypeMismatch { found, .. } if found == "std::path::PathBuf"));
+}
@@ -0,0 +1,43 @@
+use rusty_css::*;
+use rusty_css::font_face::{ FontDisplay, FontSource };
+use rusty_css::values::Length;
+
+#[test]
+fn test_font_face() {
+    let inter = FontFace::new("Inter \"Var\"")
+        .src(FontSource::local("Inter"))
+        .src(FontSource::url("/fonts/inter.woff2").format("woff2"))
+        .src(FontSource::url("/fonts/inter.ttf"))
+        .weight_range(100, 900)
+        .style("normal")
+        .display(FontDisplay::Swap)
+        .unicode_range(0x0000, 0x00ff)
+        .unicode_range(0x131, 0x131);
+    assert_eq!(
+        inter.to_css(),
+        "@font-face { font-family: \"Inter \\\"Var\\\"\"; \
+        src: local(\"Inter\"), url(\"/fonts/inter.woff2\") format(\"woff2\"), url(\"/fonts/inter.ttf\"); \
+        font-weight: 100 900; font-style: normal; font-display: swap; unicode-range: U+0000-00FF, U+0131; }"
+    );
+
+    assert_eq!(FontFace::new("Mono").weight(400).to_css(), "@font-face { font-family: \"Mono\"; font-weight: 400; }");
+}
+
+#[test]
+fn test_import() {
+    assert_eq!(Import::new("reset.css").to_css(), "@import url(\"reset.css\");");
+    assert_eq!(Import::new("base.css").layer("").to_css(), "@import url(\"base.css\") layer;");
+    assert_eq!(
+        Import::new("has.css").supports(SupportsCondition::selector(":has(a)")).to_css(),
+        "@import url(\"has.css\") supports(selector(:has(a)));"
+    );
+
+    let theme = Import::new("https://example.com/theme.css")
+        .layer("theme")
+        .supports(SupportsCondition::declaration("display", "grid"))
+        .media(MediaQuery::new().screen().min_width(Length::px(600.0)));
+    assert_eq!(
+        theme.to_css(),
+        "@import url(\"https://example.com/theme.css\") layer(theme) supports(display: grid) screen and (min-width: 600px);"
+    );
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::upper_case_acronyms)]
+
 use std::vec;
 
 use rusty_css::*;
@@ -366,7 +368,7 @@ impl Style for Realistic {
 #[test]
 fn realistic_example() {
     let css = "background: rgba(0, 255, 250, 1); background-image: linear-gradient(90deg, rgba(5, 97, 179, 1) 29%, rgba(34, 25, 0, 1) 56%, rgba(5, 97, 179, 1) 78%, rgba(34, 25, 0, 1) 56%, rgba(5, 97, 179, 1) 78%); ";
-    let output_css = "background:  rgba(0, 255, 250, 1); background-image:  linear-gradient(90deg,  rgba(5, 97, 179, 1) 29%,  rgba(34, 25, 0, 1) 56%,  rgba(5, 97, 179, 1) 78%); ";
+    let output_css = "background:  rgba(0, 255, 250, 1); background-image:  linear-gradient(90deg,  rgba(5, 97, 179, 1) 29%,  rgba(34, 25, 0, 1) 56%,  rgba(5, 97, 179, 1) 78%,  rgba(34, 25, 0, 1) 56%,  rgba(5, 97, 179, 1) 78%); ";
 
     let mut realistic_struct = Realistic::create();
     realistic_struct.set_from_inline_string(css.into());
@@ -0,0 +1,58 @@
+use rusty_css::*;
+use rusty_css::values::*;
+use bevy_reflect::{ Reflect, FromReflect };
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+enum Filter {
+    #[default]
+    None,
+    Blur(Length),
+    Brightness(f64),
+    DropShadow(Length, Length, Color),
+}
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    #[css(default = FunctionList::new(vec![Filter::Blur(Length::px(2.0)), Filter::Brightness(1.5), Filter::Blur(Length::px(4.0))]))]
+    filter: FunctionList<Filter>,
+    backdrop_filter: FunctionList<Filter>,
+}
+
+#[test]
+fn test_inline_function_list() {
+    let mut a = A::create();
+    assert_eq!(a.inline(), "filter: blur(2px) brightness(1.5) blur(4px); backdrop-filter: none; ");
+
+    a.filter.swap(0, 1);
+    a.filter.push(Filter::DropShadow(Length::px(1.0), Length::px(2.0), Color::rgb(255, 0, 0)));
+    assert_eq!(a.inline(), "filter: brightness(1.5) blur(2px) blur(4px) drop-shadow(1px 2px #ff0000); backdrop-filter: none; ");
+}
+
+#[test]
+fn test_from_string_function_list_round_trip() {
+    let mut a = A::create();
+    let css = "filter: blur(1px) blur(3px) drop-shadow(0 1em rgb(0, 0, 0)) brightness(0.5) blur(1px); backdrop-filter: brightness(2); ";
+    a.try_set_from_inline_string(css).unwrap();
+    assert_eq!(*a.filter, vec![
+        Filter::Blur(Length::px(1.0)),
+        Filter::Blur(Length::px(3.0)),
+        Filter::DropShadow(Length::px(0.0), Length::em(1.0), Color::rgb(0, 0, 0)),
+        Filter::Brightness(0.5),
+        Filter::Blur(Length::px(1.0)),
+    ]);
+    assert_eq!(*a.backdrop_filter, vec![Filter::Brightness(2.0)]);
+    assert_eq!(a.inline(), "filter: blur(1px) blur(3px) drop-shadow(0px 1em #000000) brightness(0.5) blur(1px); backdrop-filter: brightness(2); ");
+
+    a.try_set_from_inline_string("filter: none; backdrop-filter: blur(1px);").unwrap();
+    assert!(a.filter.is_empty());
+    assert_eq!(*a.backdrop_filter, vec![Filter::Blur(Length::px(1.0))]);
+}
+
+#[test]
+fn test_from_string_invalid_function_list() {
+    let mut a = A::create();
+    assert_eq!(
+        a.try_set_from_inline_string("filter: blur(1px) sepia(1);"),
+        Err(Error::Parse { offset: 18, message: "expected one of `none`, `blur`, `brightness`, `drop-shadow`, found `sepia(1)`".to_string() })
+    );
+}
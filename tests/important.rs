@@ -0,0 +1,75 @@
+use rusty_css::*;
+use rusty_css::values::*;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    width: Important<Length>,
+    #[css(default = Important::normal("red".to_string()))]
+    color: Important<String>,
+    margin: Option<Important<Vec<Length>>>,
+    background: String,
+}
+
+#[test]
+fn test_inline_important() {
+    let mut a = A::create();
+    a.width.value = Length::px(10.0);
+    assert_eq!(a.inline(), "width: 10px; color: red; background: ; ");
+
+    a.color.important = true;
+    a.margin = Some(Important::new(vec![Length::px(1.0), Length::em(2.0)]));
+    assert_eq!(a.inline(), "width: 10px; color: red !important; margin: 1px, 2em !important; background: ; ");
+}
+
+#[test]
+fn test_important_default() {
+    assert_eq!(Important::<Length>::default(), Important::normal(Length::default()));
+    assert!(!A::create().width.important);
+}
+
+#[test]
+fn test_from_string_important() {
+    let mut a = A::create();
+    a.try_set_from_inline_string("width: 2em  ! IMPORTANT; color: blue; margin: 1px, 2px !important /* ! */;").unwrap();
+    assert_eq!(a.width, Important::new(Length::em(2.0)));
+    assert_eq!(a.color, Important::normal("blue".to_string()));
+    assert_eq!(a.margin, Some(Important::new(vec![Length::px(1.0), Length::px(2.0)])));
+
+    a.try_set_from_inline_string("width: 3em; color: green!important").unwrap();
+    assert_eq!(a.width, Important::normal(Length::em(3.0)));
+    assert_eq!(a.color, Important::new("green".to_string()));
+}
+
+#[test]
+fn test_from_string_important_only_on_important_fields() {
+    // other fields keep the flag in their value
+    let mut a = A::create();
+    a.try_set_from_inline_string("background: red !important;").unwrap();
+    assert_eq!(a.background, "red !important");
+
+    // errors point into the value in front of the flag
+    assert!(matches!(
+        a.try_set_from_inline_string("width: 2pz !important;"),
+        Err(Error::Parse { offset: 8, .. })
+    ));
+}
+
+// only the Important of rusty_css is recognized, other structs with the same fields are nested structs
+
+#[derive(Reflect, bevy_reflect::FromReflect, Default, PartialEq, Debug)]
+struct Flagged {
+    value: String,
+    important: bool,
+}
+
+#[derive(Reflect, Style)]
+struct B {
+    #[css(default = Flagged { value: "red".to_string(), important: true })]
+    color: Flagged,
+}
+
+#[test]
+fn test_important_is_told_apart_from_lookalikes() {
+    assert_eq!(B::create().inline(), "color:  value(red) important(true); ");
+}
@@ -0,0 +1,47 @@
+use rusty_css::*;
+use rusty_css::values::*;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Style)]
+struct Fade {
+    opacity: f64,
+    transform: Option<Transform>,
+}
+
+#[derive(Reflect, Style)]
+struct Toast {
+    #[css(default = "fade-in 200ms")]
+    animation: String,
+}
+
+#[test]
+fn test_keyframes_from_styles() {
+    let mut start = Fade::create();
+    start.transform = Some("translateY(10px)".parse().unwrap());
+    let mut end = Fade::create();
+    end.opacity = 1.0;
+
+    let keyframes = Keyframes::new("fade-in")
+        .from(&start)
+        .frame(Percentage(50.0), &Fade { opacity: 0.8, transform: None })
+        .to(&end);
+    assert_eq!(
+        keyframes.to_css(),
+        "@keyframes fade-in { 0% { opacity: 0; transform: translateY(10px); } 50% { opacity: 0.8; } 100% { opacity: 1; } }"
+    );
+    assert_eq!(keyframes.name(), "fade-in");
+}
+
+#[test]
+fn test_keyframes_from_css() {
+    let keyframes = Keyframes::new("pulse")
+        .frame_css(Percentage(0.0), "opacity: 1")
+        .frame_css(Percentage(50.0), " opacity: 0.5; ")
+        .frame_css(Percentage(100.0), "");
+    assert_eq!(keyframes.to_css(), "@keyframes pulse { 0% { opacity: 1; } 50% { opacity: 0.5; } 100% { } }");
+
+    // the name goes into the animation of another style
+    let mut toast = Toast::create();
+    toast.animation = format!("{} 1s infinite", keyframes.name());
+    assert_eq!(toast.inline(), "animation: pulse 1s infinite; ");
+}
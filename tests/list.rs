@@ -0,0 +1,107 @@
+use rusty_css::*;
+use rusty_css::values::*;
+use bevy_reflect::{ Reflect, FromReflect };
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+struct Shadow {
+    inset: String,
+    blur: String,
+}
+
+#[derive(Reflect, FromReflect, PartialEq, Debug)]
+struct Layer {
+    inset: String,
+    blur: String,
+}
+
+impl Default for Layer {
+    fn default() -> Self {
+        Layer { inset: "none".to_string(), blur: "0".to_string() }
+    }
+}
+
+#[derive(Reflect, FromReflect, PartialEq, Debug)]
+struct Layers {
+    layer: Vec<Layer>,
+}
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+struct Unregistered {
+    blur: String,
+}
+
+#[derive(Reflect, FromReflect, PartialEq, Debug)]
+struct Stack {
+    unregistered: Vec<Unregistered>,
+}
+
+#[derive(Reflect, FromReflect, PartialEq, Debug)]
+struct NoDefault {
+    func1: String,
+}
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    font_family: Vec<String>,
+    transition_duration: Vec<Time>,
+    box_shadow: Vec<Shadow>,
+    #[css(default = Layers { layer: Vec::new() })]
+    layers: Layers,
+    #[css(default = Stack { unregistered: Vec::new() })]
+    stack: Stack,
+    no_default: Vec<NoDefault>,
+}
+
+#[test]
+fn test_from_string_grows_lists() {
+    let mut a = A::create();
+    a.try_set_from_inline_string("font-family: Foo, \"Bar Baz\", serif; transition-duration: 1s, 200ms, 0.5s;").unwrap();
+    assert_eq!(a.font_family, vec!["Foo".to_string(), "\"Bar Baz\"".to_string(), "serif".to_string()]);
+    assert_eq!(a.transition_duration, vec![Time::s(1.0), Time::ms(200.0), Time::s(0.5)]);
+
+    a.try_set_from_inline_string("font-family: mono;").unwrap();
+    assert_eq!(a.font_family, vec!["mono".to_string()]);
+}
+
+#[test]
+fn test_from_string_grows_lists_of_structs() {
+    let mut a = A::create();
+    a.try_set_from_inline_string("box-shadow: blur(1px), inset(yes) blur(2px), blur(3px);").unwrap();
+    assert_eq!(a.box_shadow, vec![
+        Shadow { inset: "".to_string(), blur: "1px".to_string() },
+        Shadow { inset: "yes".to_string(), blur: "2px".to_string() },
+        Shadow { inset: "".to_string(), blur: "3px".to_string() },
+    ]);
+    assert_eq!(a.inline(), "font-family: ; transition-duration: ; box-shadow:  inset() blur(1px),  inset(yes) blur(2px),  inset() blur(3px); layers:  layer(); stack:  unregistered(); no-default: ; ");
+}
+
+#[test]
+fn test_from_string_grows_empty_nested_lists_from_the_registered_default() {
+    // lists inside of nested structs make their new elements from the default of the registered item type
+    register_default::<Layer>();
+    let mut a = A::create();
+    a.try_set_from_inline_string("layers: layer(blur(1px), inset(yes) blur(2px));").unwrap();
+    assert_eq!(a.layers.layer, vec![
+        Layer { inset: "none".to_string(), blur: "1px".to_string() },
+        Layer { inset: "yes".to_string(), blur: "2px".to_string() },
+    ]);
+
+    // nothing is carried over from the elements that are already there
+    a.try_set_from_inline_string("layers: layer(inset(a) blur(b), blur(3px), blur(4px));").unwrap();
+    assert_eq!(a.layers.layer[2], Layer { inset: "none".to_string(), blur: "4px".to_string() });
+
+    // item types that weren't registered can't be made
+    assert!(matches!(
+        a.try_set_from_inline_string("stack: unregistered(blur(1px));"),
+        Err(Error::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_from_string_cant_grow_empty_list_without_default() {
+    let mut a = A::create();
+    assert!(matches!(
+        a.try_set_from_inline_string("no-default: func1(a);"),
+        Err(Error::TypeMismatch { .. })
+    ));
+}
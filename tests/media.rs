@@ -0,0 +1,62 @@
+use rusty_css::*;
+use rusty_css::media::{ ColorScheme, Orientation };
+use rusty_css::values::Length;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Style)]
+struct Card {
+    #[css(default = "100%")]
+    width: String,
+    #[css(media = MediaQuery::new().screen().min_width(Length::px(600.0)))]
+    wide: WideCard,
+    #[css(nest = "&:hover", media = "(hover: hover)")]
+    hover: Dark,
+}
+
+#[derive(Reflect, Style)]
+struct WideCard {
+    #[css(default = "50%")]
+    width: String,
+}
+
+#[derive(Reflect, Style)]
+#[css(media = MediaQuery::new().prefers_color_scheme(ColorScheme::Dark))]
+struct Dark {
+    #[css(default = "black")]
+    background: String,
+}
+
+#[derive(Reflect, Style)]
+#[css(selector = "::after", media = "print")]
+struct PrintOnly {
+    #[css(default = "\"!\"")]
+    content: String,
+}
+
+#[test]
+fn test_media_query_builder() {
+    let query = MediaQuery::new()
+        .min_width(Length::px(600.0))
+        .max_width(Length::em(80.0))
+        .orientation(Orientation::Landscape)
+        .prefers_reduced_motion();
+    assert_eq!(query.to_string(), "(min-width: 600px) and (max-width: 80em) and (orientation: landscape) and (prefers-reduced-motion: reduce)");
+    assert_eq!(MediaQuery::new().print().min_height(Length::cm(10.0)).to_string(), "print and (min-height: 10cm)");
+    assert_eq!(MediaQuery::new().to_string(), "all");
+    assert_eq!(MediaQuery::raw("(pointer: coarse)").wrap(".A { }"), "@media (pointer: coarse) { .A { } }");
+}
+
+#[test]
+fn test_class_string_with_media_queries() {
+    let card = Card::create();
+    assert_eq!(card.inline(), "width: 100%; ");
+    assert_eq!(
+        card.as_class_string("Card").unwrap(),
+        ".Card { width: 100%; } \
+        @media screen and (min-width: 600px) { .Card { width: 50%; } } \
+        @media (hover: hover) { .Card:hover { background: black; } }"
+    );
+
+    assert_eq!(Dark::create().as_class_string("Dark").unwrap(), "@media (prefers-color-scheme: dark) { .Dark { background: black; } }");
+    assert_eq!(PrintOnly::create().as_class_string("P").unwrap(), "@media print { .P::after { content: \"!\"; } }");
+}
@@ -0,0 +1,62 @@
+use rusty_css::*;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Style)]
+struct IconStyle {
+    #[css(default = "1em")]
+    width: String,
+    #[css(nest = "&:hover")]
+    hover: HoverStyle,
+}
+
+#[derive(Reflect, Style)]
+struct HoverStyle {
+    #[css(default = "blue")]
+    color: String,
+}
+
+#[derive(Reflect, Style)]
+struct Button {
+    #[css(default = "red")]
+    color: String,
+    #[css(nest = "&:hover, &:focus-visible")]
+    hover: HoverStyle,
+    #[css(nest = "& > .icon")]
+    icon: IconStyle,
+    #[css(nest = "span[data-x=\"&\"]", default = "color: green")]
+    label: HoverStyle,
+}
+
+#[test]
+fn test_class_string_with_nested_rules() {
+    let button = Button::create();
+    assert_eq!(button.inline(), "color: red; ");
+    assert_eq!(
+        button.as_class_string("Button").unwrap(),
+        ".Button { color: red; } \
+        .Button:hover, .Button:focus-visible { color: blue; } \
+        .Button > .icon { width: 1em; } \
+        .Button > .icon:hover { color: blue; } \
+        .Button span[data-x=\"&\"] { color: green; }"
+    );
+}
+
+#[test]
+fn test_from_string_ignores_nested_fields() {
+    let mut button = Button::create();
+    assert!(matches!(button.try_set_from_inline_string("hover: x;"), Err(Error::UnknownProperty(_))));
+
+    button.icon.hover.color = "black".to_string();
+    assert!(button.as_class_string("B").unwrap().ends_with(".B > .icon:hover { color: black; } .B span[data-x=\"&\"] { color: green; }"));
+}
+
+#[test]
+fn test_nest_selectors() {
+    assert_eq!(selector::nest("&:hover", ".A"), ".A:hover");
+    assert_eq!(selector::nest(" li ", ".A"), ".A li");
+    assert_eq!(selector::nest("& + &", ".A:focus"), ".A:focus + .A:focus");
+
+    // every selector of a list is nested on its own
+    assert_eq!(selector::nest("a, b", ".A"), ".A a, .A b");
+    assert_eq!(selector::nest("&:hover, &:focus", ".A, .B"), ".A:hover, .B:hover, .A:focus, .B:focus");
+}
@@ -0,0 +1,92 @@
+use rusty_css::*;
+use bevy_reflect::{ Reflect };
+
+// numbers and bools without units
+
+#[derive(Reflect, PartialEq, Debug)]
+struct A {
+    opacity: f32,
+    z_index: i32,
+    flex_grow: u8,
+    line_height: f64,
+    visible: bool,
+}
+
+impl Style for A {
+    fn create() -> Self {
+        Self {
+            opacity: 0.5,
+            z_index: -2,
+            flex_grow: 1,
+            line_height: 1.25,
+            visible: true,
+        }
+    }
+}
+
+#[test]
+fn test_inline_numbers() {
+    let a = A::create();
+    assert_eq!(a.inline(), "opacity: 0.5; z-index: -2; flex-grow: 1; line-height: 1.25; visible: true; ");
+}
+
+#[test]
+fn test_from_string_numbers() {
+    let mut a = A::create();
+    a.try_set_from_inline_string("opacity: 1; z-index: 10; flex-grow: 3; line-height: 2.5; visible: false;").unwrap();
+    assert_eq!(a, A { opacity: 1.0, z_index: 10, flex_grow: 3, line_height: 2.5, visible: false });
+}
+
+#[test]
+fn test_from_string_invalid_number() {
+    let mut a = A::create();
+    assert_eq!(
+        a.try_set_from_inline_string("flex-grow: 300;"),
+        Err(Error::Parse { offset: 11, message: "expected u8 number, found `300`".to_string() })
+    );
+    assert!(a.try_set_from_inline_string("opacity: 10px;").is_err());
+    assert!(a.try_set_from_inline_string("visible: yes;").is_err());
+}
+
+// numbers with units
+
+#[allow(non_snake_case)]
+#[derive(Reflect, Default, PartialEq, Debug)]
+struct Skew {
+    skewX: f32,
+    skewY: f32,
+}
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct B {
+    #[css(unit = "px", default = 200.0)]
+    width: f32,
+    #[css(unit = "em", default = "1.5em 2em")]
+    padding: (f32, f32),
+    #[css(unit = "deg", default = "skewX(20deg) skewY(30deg)")]
+    transform: Skew,
+    #[css(default = 0.5)]
+    opacity: f64,
+}
+
+#[test]
+fn test_inline_units() {
+    let b = B::create();
+    assert_eq!(b.inline(), "width: 200px; padding: 1.5em 2em; transform:  skewX(20deg) skewY(30deg); opacity: 0.5; ");
+}
+
+#[test]
+fn test_from_string_units() {
+    let mut b = B::create();
+    b.try_set_from_inline_string("width: 12.5px; padding: 0 1em; transform: skewY(-5deg)").unwrap();
+    assert_eq!(b, B { width: 12.5, padding: (0.0, 1.0), transform: Skew { skewX: 20.0, skewY: -5.0 }, opacity: 0.5 });
+}
+
+#[test]
+fn test_from_string_wrong_unit() {
+    let mut b = B::create();
+    assert_eq!(
+        b.try_set_from_inline_string("width: 12.5em"),
+        Err(Error::Parse { offset: 7, message: "expected f32 number in px, found `12.5em`".to_string() })
+    );
+}
@@ -0,0 +1,81 @@
+use rusty_css::*;
+use bevy_reflect::{ Reflect, FromReflect };
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+struct NStruct {
+    func1: String,
+    func2: String,
+}
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    #[css(default = "200px")]
+    width: Option<String>,
+    height: Option<String>,
+    #[css(unit = "px")]
+    top: Option<f32>,
+    nested: Option<NStruct>,
+}
+
+#[test]
+fn test_inline_skips_none() {
+    let a = A::create();
+    assert_eq!(a, A { width: Some("200px".to_string()), height: None, top: None, nested: None });
+    assert_eq!(a.inline(), "width: 200px; ");
+}
+
+#[test]
+fn test_inline_some() {
+    let mut a = A::create();
+    a.top = Some(10.0);
+    a.nested = Some(NStruct { func1: "a".to_string(), func2: "b".to_string() });
+    assert_eq!(a.inline(), "width: 200px; top: 10px; nested:  func1(a) func2(b); ");
+}
+
+#[test]
+fn test_from_string_fills_none() {
+    let mut a = A::create();
+    a.try_set_from_inline_string("height: 10em; top: 5px; nested: func2(val2);").unwrap();
+    assert_eq!(a.height, Some("10em".to_string()));
+    assert_eq!(a.top, Some(5.0));
+    assert_eq!(a.nested, Some(NStruct { func1: "".to_string(), func2: "val2".to_string() }));
+}
+
+#[test]
+fn test_from_string_keeps_none_on_error() {
+    let mut a = A::create();
+    assert!(a.try_set_from_inline_string("top: 5em;").is_err());
+    assert_eq!(a.top, None);
+}
+
+// options inside of nested structs, written by hand
+
+#[derive(Reflect, FromReflect, PartialEq, Debug)]
+struct OptionalFunctions {
+    func1: Option<String>,
+    func2: Option<String>,
+}
+
+#[derive(Reflect, PartialEq, Debug)]
+struct B {
+    nested: OptionalFunctions,
+    list: Vec<Option<String>>,
+}
+
+impl Style for B {
+    fn create() -> Self {
+        Self {
+            nested: OptionalFunctions { func1: None, func2: Some("b".to_string()) },
+            list: vec![Some("a".to_string()), None, Some("c".to_string())],
+        }
+    }
+}
+
+#[test]
+fn test_nested_options() {
+    let mut b = B::create();
+    assert_eq!(b.inline(), "nested:  func2(b); list: a, c; ");
+
+    b.try_set_from_inline_string("nested: func1(a)").unwrap();
+    assert_eq!(b.nested, OptionalFunctions { func1: Some("a".to_string()), func2: Some("b".to_string()) });
+}
@@ -0,0 +1,79 @@
+use rusty_css::*;
+use bevy_reflect::Reflect;
+
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Reflect, Style)]
+struct Button {
+    #[css(default = "4px")]
+    border_radius: String,
+}
+
+#[derive(Reflect, Style)]
+#[allow(non_camel_case_types)]
+struct Button_hover {
+    #[css(default = "0.8")]
+    opacity: String,
+}
+
+#[test]
+fn test_as_class_into_memory_registry() {
+    let mut registry = MemoryRegistry::new();
+    let mut button = Button::create();
+    assert_eq!(button.as_class(&mut registry).unwrap(), "registry-Button");
+    Button_hover::create().add_as_pseudo_class(&mut registry);
+    let name = Keyframes::new("spin").frame_css(values::Percentage(100.0), "rotate: 1turn").append_to_head(&mut registry);
+    assert_eq!(name, "spin");
+
+    assert_eq!(registry.len(), 3);
+    assert_eq!(registry.get("registry-Button"), Some(".registry-Button { border-radius: 4px; }"));
+    assert_eq!(
+        registry.to_css(),
+        ".registry-Button { border-radius: 4px; }\n.registry-Button:hover { opacity: 0.8; }\n@keyframes spin { 100% { rotate: 1turn; } }"
+    );
+
+    // the same class replaces its css
+    button.border_radius = "8px".to_string();
+    button.as_class(&mut registry).unwrap();
+    assert_eq!(registry.len(), 3);
+    assert_eq!(registry.get("registry-Button"), Some(".registry-Button { border-radius: 8px; }"));
+}
+
+#[test]
+fn test_memory_registry_to_html() {
+    let mut registry = MemoryRegistry::new();
+    Button_hover::create().add_as_pseudo_class(&mut registry);
+    registry.insert("quote", "a::after { content: \"</style>\"; }");
+
+    assert_eq!(
+        registry.to_html(),
+        "<style id=\"rusty-css-registry-Button_hover\">.registry-Button:hover { opacity: 0.8; }</style>\
+        <style id=\"rusty-css-quote\">a::after { content: \"<\\/style>\"; }</style>"
+    );
+
+    registry.clear();
+    assert!(registry.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn test_hydrate_server_rendered_class() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let mut registry = MemoryRegistry::new();
+    Button::create().as_class(&mut registry).unwrap();
+    let quote = "a::after { content: \"</style>\"; }";
+    registry.insert("quote", quote);
+
+    // the element rendered on the server is kept instead of being inserted again
+    let head = document.head().unwrap();
+    head.insert_adjacent_html("beforeend", &registry.to_html()).unwrap();
+    let server_element = document.get_element_by_id("rusty-css-registry-Button").unwrap();
+    Button::create().as_class(&document).unwrap();
+    assert!(document.get_element_by_id("rusty-css-registry-Button").unwrap() == server_element);
+
+    // css that had to be escaped in the html is kept too
+    let server_quote = document.get_element_by_id("rusty-css-quote").unwrap();
+    (&document).insert("quote", quote).unwrap();
+    assert!(document.get_element_by_id("rusty-css-quote").unwrap() == server_quote);
+}
@@ -0,0 +1,77 @@
+use rusty_css::*;
+use bevy_reflect::{ Reflect, FromReflect };
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+struct Shape {
+    r#box: String,
+    border_radius: String,
+}
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    #[css(rename = "-webkit-line-clamp", default = 3)]
+    line_clamp: i32,
+    #[css(rename = "font_weight_raw")]
+    weight: String,
+    r#type: String,
+    #[css(rename = "shape-outside", default = "box(margin) border-radius(2px)")]
+    shape: Shape,
+}
+
+#[test]
+fn test_inline_renamed_fields() {
+    let a = A::create();
+    assert_eq!(a.inline(), "-webkit-line-clamp: 3; font_weight_raw: ; type: ; shape-outside:  box(margin) border-radius(2px); ");
+    assert_eq!(a.as_class_string("A").unwrap(), format!(".A {{ {}}}", a.inline()));
+}
+
+#[test]
+fn test_from_string_renamed_fields() {
+    let mut a = A::create();
+    a.try_set_from_inline_string("-webkit-line-clamp: 5; font_weight_raw: bold; type: text; shape-outside: box(content);").unwrap();
+    assert_eq!(a, A {
+        line_clamp: 5,
+        weight: "bold".to_string(),
+        r#type: "text".to_string(),
+        shape: Shape { r#box: "content".to_string(), border_radius: "2px".to_string() },
+    });
+
+    // the field names aren't properties anymore
+    assert!(matches!(a.try_set_from_inline_string("line-clamp: 1;"), Err(Error::UnknownProperty(_))));
+    assert!(matches!(a.try_set_from_inline_string("weight: 1;"), Err(Error::UnknownProperty(_))));
+}
+
+// nested structs that derive Style too can rename the functions their fields are written as
+
+#[derive(Reflect, FromReflect, Style, Default, PartialEq, Debug)]
+struct Skew {
+    #[css(rename = "skewX")]
+    skew_x: String,
+    #[css(rename = "skewY")]
+    skew_y: String,
+}
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct B {
+    #[css(default = "skewX(20deg) skewY(30deg)")]
+    transform: Skew,
+    layers: Vec<Skew>,
+}
+
+#[test]
+fn test_renamed_nested_function_fields() {
+    let mut b = B::create();
+    assert_eq!(b.transform, Skew { skew_x: "20deg".to_string(), skew_y: "30deg".to_string() });
+
+    b.try_set_from_inline_string("transform: skewY(5deg); layers: skewX(1deg), skewY(2deg);").unwrap();
+    assert_eq!(b.transform.skew_y, "5deg");
+    assert_eq!(b.layers, vec![
+        Skew { skew_x: "1deg".to_string(), skew_y: "".to_string() },
+        Skew { skew_x: "".to_string(), skew_y: "2deg".to_string() },
+    ]);
+    assert_eq!(b.inline(), "transform:  skewX(20deg) skewY(5deg); layers:  skewX(1deg) skewY(),  skewX() skewY(2deg); ");
+
+    // the field names aren't functions anymore
+    b.try_set_from_inline_string("transform: skew-x(9deg);").unwrap();
+    assert_eq!(b.transform.skew_x, "20deg");
+}
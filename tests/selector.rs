@@ -0,0 +1,58 @@
+use rusty_css::*;
+use rusty_css::selector::{ AttributeMatcher, Combinator, SelectorPart };
+use bevy_reflect::Reflect;
+
+#[test]
+fn test_selector_builder() {
+    let selector = Selector::new()
+        .hover()
+        .attribute_matches("data-state", AttributeMatcher::Prefix, "op\"en")
+        .child()
+        .element("li")
+        .pseudo_class("nth-child(2n + 1)")
+        .after();
+    assert_eq!(selector.to_string(), ":hover[data-state^=\"op\\\"en\"] > li:nth-child(2n + 1)::after");
+    assert_eq!(selector.to_string().parse::<Selector>().unwrap(), selector);
+}
+
+#[test]
+fn test_selector_from_str() {
+    let selector = " .item  >  a[href]:not(.external, [target=_blank]) ~ #main *".parse::<Selector>().unwrap();
+    assert_eq!(selector.parts, vec![
+        SelectorPart::Combinator(Combinator::Descendant),
+        SelectorPart::Class("item".to_string()),
+        SelectorPart::Combinator(Combinator::Child),
+        SelectorPart::Type("a".to_string()),
+        SelectorPart::Attribute { name: "href".to_string(), matcher: None },
+        SelectorPart::PseudoClass("not(.external, [target=_blank])".to_string()),
+        SelectorPart::Combinator(Combinator::SubsequentSibling),
+        SelectorPart::Id("main".to_string()),
+        SelectorPart::Combinator(Combinator::Descendant),
+        SelectorPart::Universal,
+    ]);
+    assert_eq!(selector.to_string(), " .item > a[href]:not(.external, [target=_blank]) ~ #main *");
+
+    assert!(matches!(":".parse::<Selector>(), Err(Error::Parse { offset: 1, .. })));
+    assert!(matches!("[a=b c]".parse::<Selector>(), Err(Error::Parse { offset: 0, .. })));
+    assert!(matches!(":hover, :focus".parse::<Selector>(), Err(Error::Parse { offset: 6, .. })));
+}
+
+#[derive(Reflect, Style)]
+#[css(selector = "::before")]
+struct Icon {
+    #[css(default = "\"*\"")]
+    content: String,
+}
+
+#[derive(Reflect, Style)]
+#[css(selector = Selector::new().focus().child().class("label"))]
+struct Field {
+    #[css(default = "red")]
+    color: String,
+}
+
+#[test]
+fn test_class_string_with_selector() {
+    assert_eq!(Icon::create().as_class_string("Icon").unwrap(), ".Icon::before { content: \"*\"; }");
+    assert_eq!(Field::create().as_class_string("Field").unwrap(), ".Field:focus > .label { color: red; }");
+}
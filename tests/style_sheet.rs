@@ -0,0 +1,186 @@
+use rusty_css::*;
+use bevy_reflect::Reflect;
+use wasm_bindgen::JsCast;
+
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Reflect, Style)]
+struct Card {
+    #[css(default = "10px")]
+    padding: String,
+    #[css(nest = "&:hover")]
+    hover: CardHover,
+}
+
+#[derive(Reflect, Style)]
+struct CardHover {
+    #[css(default = "0.8")]
+    opacity: String,
+}
+
+#[derive(Reflect, Style)]
+#[css(media = "print")]
+struct Hidden {
+    #[css(default = "none")]
+    display: String,
+}
+
+#[test]
+fn test_style_sheet_to_css() {
+    let mut sheet = StyleSheet::new();
+    assert_eq!(sheet.add_class(&Card::create()).unwrap(), "style_sheet-Card");
+    assert_eq!(sheet.add_class(&Hidden::create()).unwrap(), "style_sheet-Hidden");
+    assert_eq!(sheet.add_keyframes(&Keyframes::new("fade").frame_css(values::Percentage(0.0), "opacity: 0")), "fade");
+    sheet.add_import(&Import::new("reset.css"));
+
+    assert_eq!(
+        sheet.to_css(),
+        "@import url(\"reset.css\");\n\
+        .style_sheet-Card { padding: 10px; } .style_sheet-Card:hover { opacity: 0.8; }\n\
+        @media print { .style_sheet-Hidden { display: none; } }\n\
+        @keyframes fade { 0% { opacity: 0; } }"
+    );
+}
+
+#[test]
+fn test_style_sheet_dedupes_and_replaces() {
+    let mut sheet = StyleSheet::new();
+    let mut card = Card::create();
+    sheet.add_class(&card).unwrap();
+    sheet.add_class(&card).unwrap();
+    assert_eq!(sheet.len(), 1);
+
+    // adding a changed style replaces its rules where they were
+    sheet.add_rule(".other", ".other { color: red; }");
+    card.padding = "20px".to_string();
+    sheet.add_class(&card).unwrap();
+    assert_eq!(sheet.to_css(), ".style_sheet-Card { padding: 20px; } .style_sheet-Card:hover { opacity: 0.8; }\n.other { color: red; }");
+
+    assert!(sheet.remove(".style_sheet-Card"));
+    assert!(!sheet.remove(".style_sheet-Card"));
+    assert!(!sheet.contains(".style_sheet-Card"));
+    assert_eq!(sheet.to_css(), ".other { color: red; }");
+}
+
+#[test]
+fn test_style_sheet_imports_come_first() {
+    let mut sheet = StyleSheet::new();
+    sheet.add_font_face(&FontFace::new("Inter"));
+    sheet.add_import(&Import::new("a.css"));
+    sheet.add_import(&Import::new("b.css"));
+    sheet.add_import(&Import::new("a.css").layer("base"));
+
+    assert_eq!(
+        sheet.to_css(),
+        "@import url(\"a.css\") layer(base);\n@import url(\"b.css\");\n@font-face { font-family: \"Inter\"; }"
+    );
+}
+
+#[test]
+fn test_style_sheet_replaces_font_faces_of_the_same_family_weight_and_style() {
+    let mut sheet = StyleSheet::new();
+    sheet.add_font_face(&FontFace::new("Inter").weight(400).src(font_face::FontSource::local("Inter")));
+    sheet.add_font_face(&FontFace::new("Inter").weight(700).src(font_face::FontSource::local("Inter Bold")));
+    sheet.add_font_face(&FontFace::new("Inter").weight(400).src(font_face::FontSource::local("Inter Regular")));
+
+    assert_eq!(sheet.len(), 2);
+    assert!(sheet.contains(&FontFace::new("Inter").weight(400).key()));
+    assert_eq!(
+        sheet.to_css(),
+        "@font-face { font-family: \"Inter\"; src: local(\"Inter Regular\"); font-weight: 400; }\n\
+        @font-face { font-family: \"Inter\"; src: local(\"Inter Bold\"); font-weight: 700; }"
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_style_sheet_flush() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let mut sheet = StyleSheet::with_id("style-sheet-test");
+    sheet.add_class(&Card::create()).unwrap();
+    sheet.flush(&document);
+
+    sheet.add_class(&Hidden::create()).unwrap();
+    sheet.remove(".style_sheet-Card");
+    sheet.flush(&document);
+
+    let elements = document.query_selector_all("#style-sheet-test").unwrap();
+    assert_eq!(elements.length(), 1);
+    let style = elements.get(0).unwrap()
+        .dyn_into::<web_sys::HtmlStyleElement>().unwrap()
+        .sheet().unwrap()
+        .dyn_into::<web_sys::CssStyleSheet>().unwrap();
+    let rules = style.css_rules().unwrap();
+    assert_eq!(rules.length(), 1);
+    assert!(rules.get(0).unwrap().css_text().starts_with("@media print"));
+}
+
+// the css text of the rules in the <style> element with the given id
+fn css_rules(document: &web_sys::Document, id: &str) -> Vec<String> {
+    let rules = document.get_element_by_id(id).unwrap()
+        .dyn_into::<web_sys::HtmlStyleElement>().unwrap()
+        .sheet().unwrap()
+        .dyn_into::<web_sys::CssStyleSheet>().unwrap()
+        .css_rules().unwrap();
+    (0..rules.length()).map(|i| rules.get(i).unwrap().css_text()).collect()
+}
+
+#[wasm_bindgen_test]
+fn test_style_sheet_flush_moved_entries() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let mut sheet = StyleSheet::with_id("style-sheet-moved");
+    sheet.add_rule(".a", ".a { color: red; }");
+    sheet.add_rule(".b", ".b { color: blue; }");
+    sheet.add_rule(".c", ".c { color: green; }");
+    sheet.flush(&document);
+
+    // an entry that is removed and added again moves to the end, without leaving its old rule behind
+    sheet.remove(".a");
+    sheet.add_rule(".a", ".a { color: red; }");
+    sheet.flush(&document);
+    let rules = css_rules(&document, "style-sheet-moved");
+    assert_eq!(rules.len(), 3);
+    assert!(rules[0].starts_with(".b"));
+    assert!(rules[1].starts_with(".c"));
+    assert!(rules[2].starts_with(".a"));
+
+    sheet.remove(".c");
+    sheet.flush(&document);
+    let rules = css_rules(&document, "style-sheet-moved");
+    assert_eq!(rules.len(), 2);
+    assert!(rules[0].starts_with(".b"));
+    assert!(rules[1].starts_with(".a"));
+}
+
+#[wasm_bindgen_test]
+fn test_style_sheet_flush_rejected_rule() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let mut sheet = StyleSheet::with_id("style-sheet-rejected");
+    sheet.add_rule(".a", ".a { color: red; }");
+    sheet.flush(&document);
+
+    // the browser rejects the rule with the invalid selector, the rules around it still end up where they belong
+    sheet.add_rule(".bad", "..bad { color: red; }");
+    sheet.add_rule(".b", ".b { color: blue; }");
+    sheet.flush(&document);
+    assert_eq!(css_rules(&document, "style-sheet-rejected").len(), 2);
+
+    sheet.remove(".a");
+    sheet.flush(&document);
+    let rules = css_rules(&document, "style-sheet-rejected");
+    assert_eq!(rules.len(), 1);
+    assert!(rules[0].starts_with(".b"));
+
+    // the same when the rejected rule was written into the element as text
+    let mut sheet = StyleSheet::with_id("style-sheet-rejected-text");
+    sheet.add_rule(".bad", "..bad { color: red; }");
+    sheet.add_rule(".a", ".a { color: red; }");
+    sheet.add_rule(".b", ".b { color: blue; }");
+    sheet.flush(&document);
+    sheet.remove(".a");
+    sheet.flush(&document);
+    let rules = css_rules(&document, "style-sheet-rejected-text");
+    assert_eq!(rules.len(), 1);
+    assert!(rules[0].starts_with(".b"));
+}
@@ -0,0 +1,66 @@
+use rusty_css::*;
+use rusty_css::tokenizer::{ tokenize, TokenKind };
+use bevy_reflect::{ Reflect, FromReflect };
+
+fn kinds(css: &str) -> Vec<TokenKind> {
+    tokenize(css).into_iter().map(|token| token.kind).collect()
+}
+
+#[test]
+fn test_tokenize() {
+    assert_eq!(kinds("width: 10px !important;"), vec![
+        TokenKind::Ident, TokenKind::Colon, TokenKind::Whitespace, TokenKind::Dimension(10.0), TokenKind::Whitespace,
+        TokenKind::Delim('!'), TokenKind::Ident, TokenKind::Semicolon,
+    ]);
+    assert_eq!(kinds("rgb(0,50%,-.5e1)"), vec![
+        TokenKind::Function, TokenKind::Number(0.0), TokenKind::Comma, TokenKind::Percentage(50.0), TokenKind::Comma,
+        TokenKind::Number(-5.0), TokenKind::CloseParen,
+    ]);
+    assert_eq!(kinds("url(a,b) url( \"c\" ) url(d e)"), vec![
+        TokenKind::Url, TokenKind::Whitespace, TokenKind::Function, TokenKind::Whitespace, TokenKind::String,
+        TokenKind::Whitespace, TokenKind::CloseParen, TokenKind::Whitespace, TokenKind::BadUrl,
+    ]);
+    assert_eq!(kinds("#fff @media --x -->"), vec![
+        TokenKind::Hash, TokenKind::Whitespace, TokenKind::AtKeyword, TokenKind::Whitespace, TokenKind::Ident,
+        TokenKind::Whitespace, TokenKind::Cdc,
+    ]);
+}
+
+#[test]
+fn test_tokenize_strings_comments_and_escapes() {
+    let css = "\"a\\\"b, c\" /* ) ; */ \\31 0px 'd\ne'";
+    let tokens = tokenize(css);
+    let texts = tokens.iter().map(|token| token.text(css)).collect::<Vec<&str>>();
+    assert_eq!(texts, vec!["\"a\\\"b, c\"", " ", "/* ) ; */", " ", "\\31 0px", " ", "'d", "\n", "e", "'"]);
+    assert_eq!(tokens[4].kind, TokenKind::Ident);
+    assert_eq!(tokens[6].kind, TokenKind::BadString);
+}
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+struct Background {
+    url: String,
+}
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    #[css(default = vec![String::new(); 2])]
+    font_family: Vec<String>,
+    content: String,
+    background_image: Background,
+    #[css(default = (String::new(), String::new()))]
+    margin: (String, String),
+}
+
+#[test]
+fn test_from_string_quotes_comments_and_urls() {
+    let mut a = A::create();
+    let css = "font-family: \"Foo, Bar\", 'Baz (Serif)'; content: \";)\"; /* width: 10px; */ background-image: url(a,b); margin: /* top */ 1px /* right */ 2px;";
+    a.try_set_from_inline_string(css).unwrap();
+    assert_eq!(a, A {
+        font_family: vec!["\"Foo, Bar\"".to_string(), "'Baz (Serif)'".to_string()],
+        content: "\";)\"".to_string(),
+        background_image: Background { url: "a,b".to_string() },
+        margin: ("1px".to_string(), "2px".to_string()),
+    });
+    assert_eq!(a.inline(), "font-family: \"Foo, Bar\", 'Baz (Serif)'; content: \";)\"; background-image:  url(a,b); margin: 1px 2px; ");
+}
@@ -0,0 +1,87 @@
+use rusty_css::*;
+use rusty_css::values::*;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    #[css(default = "translate(-50%, -50%) rotate(45deg)")]
+    transform: Transform,
+}
+
+fn assert_close(left: Matrix, right: Matrix) {
+    for (left_row, right_row) in left.iter().zip(right.iter()) {
+        for (left, right) in left_row.iter().zip(right_row.iter()) {
+            assert!((left - right).abs() < 1e-9, "{:?} != {:?}", left_row, right_row);
+        }
+    }
+}
+
+#[test]
+fn test_inline_transform() {
+    let mut a = A::create();
+    assert_eq!(a.inline(), "transform: translate(-50%, -50%) rotate(45deg); ");
+
+    a.transform = Transform::none();
+    assert_eq!(a.inline(), "transform: none; ");
+
+    a.transform = Transform::none()
+        .then(TransformFunction::TranslateX(Length::px(10.0).into()))
+        .then(TransformFunction::Scale(1.5, 1.5))
+        .then(TransformFunction::SkewY(Angle::deg(5.0)));
+    assert_eq!(a.inline(), "transform: translateX(10px) scale(1.5, 1.5) skewY(5deg); ");
+}
+
+#[test]
+fn test_from_string_transform_keeps_order() {
+    let mut a = A::create();
+    a.try_set_from_inline_string("transform: rotate(10deg) translateX(2em) rotate(20deg) scale(150%);").unwrap();
+    assert_eq!(a.transform, Transform(vec![
+        TransformFunction::Rotate(Angle::deg(10.0)),
+        TransformFunction::TranslateX(Length::em(2.0).into()),
+        TransformFunction::Rotate(Angle::deg(20.0)),
+        TransformFunction::Scale(1.5, 1.5),
+    ]));
+    assert_eq!(a.inline(), "transform: rotate(10deg) translateX(2em) rotate(20deg) scale(1.5, 1.5); ");
+
+    a.try_set_from_inline_string("transform: none;").unwrap();
+    assert_eq!(a.transform, Transform::none());
+}
+
+#[test]
+fn test_from_string_invalid_transform() {
+    let mut a = A::create();
+    assert_eq!(
+        a.try_set_from_inline_string("transform: rotate(10deg) spin(1turn);"),
+        Err(Error::Parse { offset: 25, message: "unknown transform function `spin()`".to_string() })
+    );
+    assert_eq!(
+        a.try_set_from_inline_string("transform: matrix(1, 0, 0, 1);"),
+        Err(Error::Parse { offset: 11, message: "expected 6 arguments to `matrix()`, found 4".to_string() })
+    );
+    assert_eq!(
+        a.try_set_from_inline_string("transform: rotate(10px);"),
+        Err(Error::Parse { offset: 20, message: "unknown unit `px` for an angle".to_string() })
+    );
+}
+
+#[test]
+fn test_transform_matrix() {
+    let transform: Transform = "translate(10px, 20px) rotate(90deg) scale(2)".parse().unwrap();
+    assert_close(transform.to_matrix().unwrap(), [
+        [0.0, -2.0, 0.0, 10.0],
+        [2.0, 0.0, 0.0, 20.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    let matrix: Transform = "matrix(1, 2, 3, 4, 5, 6)".parse().unwrap();
+    let matrix3d: Transform = "matrix3d(1, 2, 0, 0, 3, 4, 0, 0, 0, 0, 1, 0, 5, 6, 0, 1)".parse().unwrap();
+    assert_close(matrix.to_matrix().unwrap(), matrix3d.to_matrix().unwrap());
+
+    let rotate_x: Transform = "rotateX(0.25turn)".parse().unwrap();
+    let rotate3d: Transform = "rotate3d(2, 0, 0, 90deg)".parse().unwrap();
+    assert_close(rotate_x.to_matrix().unwrap(), rotate3d.to_matrix().unwrap());
+
+    assert_eq!(Transform::none().to_matrix(), Some(IDENTITY));
+    assert_eq!("translate(50%)".parse::<Transform>().unwrap().to_matrix(), None);
+}
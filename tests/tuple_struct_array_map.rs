@@ -0,0 +1,107 @@
+use rusty_css::*;
+use rusty_css::values::*;
+use bevy_reflect::{ Reflect, FromReflect };
+use std::collections::HashMap;
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+struct Px(String);
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+struct Gap(Length, Length);
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    width: Px,
+    gap: Gap,
+    margin: [String; 4],
+    inset: [Length; 2],
+    // properties without a field of their own end up in here
+    extra: HashMap<String, String>,
+    filter: HashMap<String, String>,
+}
+
+#[test]
+fn test_render_tuple_structs_arrays_and_maps() {
+    let mut a = A::create();
+    a.width = Px("10px".to_string());
+    a.gap = Gap(Length::px(1.0), Length::em(2.0));
+    a.margin = ["1px".to_string(), "2px".to_string(), "3px".to_string(), "4px".to_string()];
+    a.inset = [Length::px(0.0), Length::vw(50.0)];
+    a.filter.insert("blur".to_string(), "2px".to_string());
+    a.filter.insert("brightness".to_string(), "50%".to_string());
+    a.extra.insert("z-index".to_string(), "2".to_string());
+    a.extra.insert("--accent".to_string(), "red".to_string());
+
+    assert_eq!(
+        a.inline(),
+        "width: 10px; gap: 1px 2em; margin: 1px 2px 3px 4px; inset: 0px 50vw; --accent: red; z-index: 2; filter:  blur(2px) brightness(50%); "
+    );
+}
+
+#[test]
+fn test_from_string_tuple_structs_and_arrays() {
+    let mut a = A::create();
+    a.try_set_from_inline_string("width: 10px; gap: 1px 2em; margin: 1px 2px 3px 4px; inset: 0 50vw;").unwrap();
+    assert_eq!(a.width, Px("10px".to_string()));
+    assert_eq!(a.gap, Gap(Length::px(1.0), Length::em(2.0)));
+    assert_eq!(a.margin, ["1px".to_string(), "2px".to_string(), "3px".to_string(), "4px".to_string()]);
+    assert_eq!(a.inset, [Length::px(0.0), Length::vw(50.0)]);
+
+    assert!(matches!(
+        a.try_set_from_inline_string("inset: 1px;"),
+        Err(Error::Parse { offset: 10, .. })
+    ));
+}
+
+#[derive(Reflect, FromReflect, Default, PartialEq, Debug)]
+struct Nothing();
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct B {
+    none: [String; 0],
+    nothing: Nothing,
+}
+
+#[test]
+fn test_from_string_zero_length() {
+    // containers without elements only take css without any values
+    let mut b = B::create();
+    b.try_set_from_inline_string("none: ; nothing: /* */;").unwrap();
+    assert_eq!(
+        b.try_set_from_inline_string("none: a"),
+        Err(Error::Parse { offset: 6, message: "expected 0 space separated values, found 1".to_string() })
+    );
+    assert_eq!(
+        b.try_set_from_inline_string("nothing: a b"),
+        Err(Error::Parse { offset: 9, message: "expected 0 space separated values, found 1".to_string() })
+    );
+}
+
+#[test]
+fn test_from_string_box_shorthands() {
+    let mut a = A::create();
+    let margin = |a: &A| a.margin.iter().map(String::as_str).collect::<Vec<&str>>().join(" ");
+
+    a.try_set_from_inline_string("margin: 1px;").unwrap();
+    assert_eq!(margin(&a), "1px 1px 1px 1px");
+    a.try_set_from_inline_string("margin: 1px 2px;").unwrap();
+    assert_eq!(margin(&a), "1px 2px 1px 2px");
+    a.try_set_from_inline_string("margin: 1px 2px 3px;").unwrap();
+    assert_eq!(margin(&a), "1px 2px 3px 2px");
+}
+
+#[test]
+fn test_from_string_maps() {
+    let mut a = A::create();
+    a.try_set_from_inline_string("filter: blur(2px) drop-shadow(1px 1px red); --accent: blue; z-index: 2;").unwrap();
+    assert_eq!(a.filter.get("blur"), Some(&"2px".to_string()));
+    assert_eq!(a.filter.get("drop-shadow"), Some(&"1px 1px red".to_string()));
+
+    assert_eq!(a.extra.get("--accent"), Some(&"blue".to_string()));
+    assert_eq!(a.extra.get("z-index"), Some(&"2".to_string()));
+
+    // existing entries are parsed into, the others are kept
+    a.try_set_from_inline_string("filter: blur(4px);").unwrap();
+    assert_eq!(a.filter.get("blur"), Some(&"4px".to_string()));
+    assert_eq!(a.filter.len(), 2);
+}
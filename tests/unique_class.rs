@@ -0,0 +1,56 @@
+use rusty_css::*;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Style)]
+struct Bar {
+    #[css(default = "10px")]
+    width: String,
+    #[css(nest = "&:hover")]
+    hover: BarHover,
+}
+
+#[derive(Reflect, Style)]
+struct BarHover {
+    #[css(default = "0.5")]
+    opacity: String,
+}
+
+#[test]
+fn test_unique_class_per_distinct_values() {
+    let mut registry = MemoryRegistry::new();
+    let narrow = Bar::create();
+    let mut wide = Bar::create();
+    wide.width = "50px".to_string();
+
+    let narrow_class = narrow.as_unique_class(&mut registry).unwrap();
+    let wide_class = wide.as_unique_class(&mut registry).unwrap();
+    assert!(narrow_class.starts_with("unique_class-Bar-"));
+    assert_ne!(narrow_class, wide_class);
+
+    // both variants are kept, each of them once
+    assert_eq!(Bar::create().as_unique_class(&mut registry).unwrap(), narrow_class);
+    assert_eq!(registry.len(), 2);
+    assert_eq!(
+        registry.get(&narrow_class).unwrap(),
+        format!(".{0} {{ width: 10px; }} .{0}:hover {{ opacity: 0.5; }}", narrow_class)
+    );
+    assert_eq!(registry.get(&wide_class).unwrap(), format!(".{0} {{ width: 50px; }} .{0}:hover {{ opacity: 0.5; }}", wide_class));
+}
+
+#[test]
+fn test_unique_class_name_follows_the_css() {
+    let mut bar = Bar::create();
+    let before = bar.unique_class_name().unwrap();
+
+    // nested styles are part of the css too
+    bar.hover.opacity = "1".to_string();
+    let after = bar.unique_class_name().unwrap();
+    assert_ne!(before, after);
+
+    bar.hover.opacity = "0.5".to_string();
+    assert_eq!(bar.unique_class_name().unwrap(), before);
+
+    let mut sheet = StyleSheet::new();
+    assert_eq!(sheet.add_unique_class(&bar).unwrap(), before);
+    assert!(sheet.contains(&format!(".{}", before)));
+}
@@ -0,0 +1,97 @@
+use rusty_css::*;
+use rusty_css::values::*;
+use bevy_reflect::{ Reflect, FromReflect };
+
+#[derive(Reflect, FromReflect, PartialEq, Debug)]
+enum Rotate {
+    Rotate(Angle),
+}
+
+#[derive(Reflect, Style, PartialEq, Debug)]
+struct A {
+    #[css(default = "10px")]
+    width: Length,
+    #[css(default = "50%")]
+    height: Percentage,
+    #[css(default = "1px 2em")]
+    margin: (Length, Length),
+    #[css(default = Rotate::Rotate(Angle::deg(45.0)))]
+    transform: Rotate,
+    transition_duration: Vec<Time>,
+    min_width: Option<Length>,
+}
+
+#[test]
+fn test_inline_values() {
+    let mut a = A::create();
+    a.transition_duration = vec![Time::ms(200.0), Time::s(1.5)];
+    assert_eq!(
+        a.inline(),
+        "width: 10px; height: 50%; margin: 1px 2em; transform: rotate(45deg); transition-duration: 200ms, 1.5s; "
+    );
+}
+
+#[test]
+fn test_from_string_values() {
+    let mut a = A::create();
+    a.transition_duration = vec![Time::default(); 2];
+    a.try_set_from_inline_string("width: 2.5rem; height: 0; margin: 0 -1e1vw; transform: rotate(0.5turn); transition-duration: 1s, 20MS; min-width: 4cm;").unwrap();
+    assert_eq!(a, A {
+        width: Length::rem(2.5),
+        height: Percentage(0.0),
+        margin: (Length::px(0.0), Length::vw(-10.0)),
+        transform: Rotate::Rotate(Angle::turn(0.5)),
+        transition_duration: vec![Time::s(1.0), Time::ms(20.0)],
+        min_width: Some(Length::cm(4.0)),
+    });
+}
+
+#[test]
+fn test_from_string_invalid_values() {
+    let mut a = A::create();
+    assert_eq!(
+        a.try_set_from_inline_string("width: 10pz;"),
+        Err(Error::Parse { offset: 9, message: "unknown unit `pz` for a length".to_string() })
+    );
+    assert_eq!(
+        a.try_set_from_inline_string("width: 10;"),
+        Err(Error::Parse { offset: 9, message: "expected a unit after a length in `10`".to_string() })
+    );
+    assert_eq!(
+        a.try_set_from_inline_string("height: half;"),
+        Err(Error::Parse { offset: 8, message: "expected a percentage like `50%`, found `half`".to_string() })
+    );
+}
+
+#[test]
+fn test_parse_and_display() {
+    assert_eq!("1.5em".parse::<Length>(), Ok(Length::em(1.5)));
+    assert_eq!("-.5E2PX".parse::<Length>(), Ok(Length::px(-50.0)));
+    assert_eq!("1.5rad".parse::<Angle>(), Ok(Angle::rad(1.5)));
+    assert_eq!("300dpi".parse::<Resolution>(), Ok(Resolution::dpi(300.0)));
+    assert_eq!(Length::inches(1.0).to_string(), "1in");
+    assert_eq!(Frequency::khz(2.0).to_string(), "2khz");
+}
+
+#[test]
+fn test_arithmetic() {
+    assert_eq!(Length::px(10.0).checked_add(Length::px(5.0)), Some(Length::px(15.0)));
+    assert_eq!(Length::inches(1.0).checked_sub(Length::px(48.0)), Some(Length::inches(0.5)));
+    assert_eq!(Length::em(2.0) * 3.0 / 2.0, Length::em(3.0));
+    assert_eq!(-Length::vw(1.0), Length::vw(-1.0));
+    assert_eq!(Angle::turn(0.5).to(AngleUnit::Deg), Some(Angle::deg(180.0)));
+    assert_eq!(Time::s(1.0).checked_add(Time::ms(250.0)), Some(Time::s(1.25)));
+    assert_eq!(Percentage(25.0).of(Length::px(200.0)), Length::px(50.0));
+    assert_eq!(Length::px(1.0).checked_add(Length::em(1.0)), None);
+    assert_eq!(Length::em(1.0).to(LengthUnit::Px), None);
+}
+
+#[test]
+fn test_unitless_zero() {
+    // only lengths and angles can leave out the unit of a zero
+    assert_eq!("0".parse::<Length>(), Ok(Length::px(0.0)));
+    assert_eq!("0".parse::<Angle>(), Ok(Angle::deg(0.0)));
+    assert!(matches!("0".parse::<Time>(), Err(Error::Parse { offset: 1, .. })));
+    assert!(matches!("0".parse::<Frequency>(), Err(Error::Parse { .. })));
+    assert!(matches!("0".parse::<Resolution>(), Err(Error::Parse { .. })));
+}